@@ -23,14 +23,7 @@ vi.mock('../logic/grammar-checker', () => ({
   },
 }));
 
-vi.mock('../logic/metrics-calculator', () => ({
-  computeMetrics: vi.fn().mockReturnValue({
-    word_count: 10,
-    cefr_level: 'B2',
-    unique_words: 8,
-    complex_words: 2,
-    pronunciation_score: 90,
-  }),
+vi.mock('../logic/metrics-ml', () => ({
   computeMetricsWithML: vi.fn().mockResolvedValue({
     word_count: 10,
     cefr_level: 'B2',
@@ -38,6 +31,8 @@ vi.mock('../logic/metrics-calculator', () => ({
     complex_words: 2,
     pronunciation_score: 90,
     cefr_confidence: 0.85,
+    cefr_is_reliable: true,
+    fluency_score: 72,
     cefr_method: 'ml',
   }),
 }));
@@ -5,16 +5,27 @@ import {
   subscribeToLoadingState,
   ModelLoadingState,
 } from '../logic/local-transcriber';
-import { computeMetricsWithML } from '../logic/metrics-calculator';
+import { computeMetricsWithML, type MetricsWithConfidence } from '../logic/metrics-ml';
 import { loadCEFRClassifier, isCEFRClassifierReady } from '../logic/cefr-classifier';
 import { checkWebGPU } from '../logic/webgpu-check';
 import { TranscriptionResult } from '../logic/transcriber';
 import { GrammarChecker, AnalysisResult } from '../logic/grammar-checker';
 import { detectEnglish } from '../logic/language-detector';
+import { parseLanguageHint, toMetricsLanguage } from '../logic/language-hint';
 
 // Transcribers can be singletons for this session manager
 const localTranscriber = new LocalTranscriber();
 
+// Keeps metrics responsive for very long transcripts
+const MAX_METRICS_WORDS = 5000;
+const TRUNCATED_WARNING = `Only the first ${MAX_METRICS_WORDS} words were scored.`;
+
+function describeCefr(result: MetricsWithConfidence): string {
+  if (!result.cefr_is_reliable) return 'too short to assess';
+  if (result.cefr_method !== 'ml') return 'heuristic';
+  return `${(result.cefr_confidence * 100).toFixed(0)}% confidence`;
+}
+
 export function useSessionManager() {
   const view = useSignal<'idle' | 'recording' | 'processing' | 'results'>('idle');
   const transcript = useSignal<TranscriptionResult | null>(null);
@@ -82,6 +93,7 @@ export function useSessionManager() {
         '[SessionManager] Using LocalTranscriber.',
         selectedDeviceId.value ? `Device: ${selectedDeviceId.value}` : '(default)'
       );
+      localTranscriber.language = parseLanguageHint(window.location.search);
       localTranscriber.onProgress = (msg) => {
         statusMsg.value = msg;
       };
@@ -159,8 +171,9 @@ export function useSessionManager() {
     transcript.value = result;
 
     const detectionResult = detectEnglish(result.text);
+    const expectsEnglish = (localTranscriber.language ?? 'en') === 'en';
 
-    if (!detectionResult.isLikelyEnglish) {
+    if (expectsEnglish && !detectionResult.isLikelyEnglish) {
       console.warn(
         `[SessionManager] Non-English speech detected (confidence: ${detectionResult.confidence.toFixed(2)})`
       );
@@ -184,7 +197,11 @@ export function useSessionManager() {
         }
       }
 
-      const metricsResult = await computeMetricsWithML(result.text, result.words);
+      const metricsResult = await computeMetricsWithML(result.text, result.words, {
+        durationSecs: lastDuration.value,
+        language: toMetricsLanguage(localTranscriber.language),
+        maxWords: MAX_METRICS_WORDS,
+      });
       console.log(
         `[SessionManager] Metrics calculated (${metricsResult.cefr_method}):`,
         metricsResult
@@ -192,13 +209,10 @@ export function useSessionManager() {
 
       metrics.value = {
         word_count: metricsResult.word_count,
-        wpm: Math.round(metricsResult.word_count / (lastDuration.value / 60)),
+        wpm: Math.round(metricsResult.words_per_minute ?? 0),
         cefr_level: metricsResult.cefr_level,
-        cefr_description:
-          metricsResult.cefr_method === 'ml'
-            ? `${(metricsResult.cefr_confidence! * 100).toFixed(0)}% confidence`
-            : 'heuristic',
-        fluency_score: 0,
+        cefr_description: describeCefr(metricsResult),
+        fluency_score: Math.round(metricsResult.fluency_score),
         unique_words: metricsResult.unique_words,
         complex_words: metricsResult.complex_words,
         pronunciation_score: metricsResult.pronunciation_score,
@@ -211,6 +225,8 @@ export function useSessionManager() {
       const analysisResult = GrammarChecker.check(result.text);
       analysis.value = analysisResult;
 
+      if (result.warning) statusMsg.value = result.warning;
+      if (metricsResult.truncated) statusMsg.value = TRUNCATED_WARNING;
       view.value = 'results';
     } catch (e) {
       console.error('[SessionManager] Metrics calculation failed:', e);
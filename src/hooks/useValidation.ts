@@ -1,7 +1,7 @@
 import { useCallback } from 'preact/hooks';
 import { useSignal } from '@preact/signals';
 import { ModelSingleton } from '../logic/model-loader';
-import { computeMetricsWithML } from '../logic/metrics-calculator';
+import { computeMetricsWithML } from '../logic/metrics-ml';
 import { GrammarChecker } from '../logic/grammar-checker';
 import { loadCEFRClassifier, isCEFRClassifierReady } from '../logic/cefr-classifier';
 import { ValidationResult, DEFAULT_WHISPER_MODEL, STMEntry } from '../types/validation';
@@ -0,0 +1,29 @@
+import { describe, it, expect } from 'vitest';
+import { computeBasicMetrics } from './basic-metrics';
+import { computeMetrics } from './metrics-calculator';
+
+describe('computeBasicMetrics', () => {
+  it('matches the corresponding computeMetrics fields', () => {
+    const text = "I don't think the university is open. It closed early, unfortunately!";
+    const basic = computeBasicMetrics(text);
+    const full = computeMetrics(text);
+    for (const [field, value] of Object.entries(basic)) {
+      expect(value).toEqual(full[field as keyof typeof basic]);
+    }
+  });
+
+  it('honours the sentence split mode and keeps contractions whole', () => {
+    expect(computeBasicMetrics("I don't know").word_count).toBe(3);
+    const options = { sentenceSplitMode: 'heuristic' as const };
+    expect(computeBasicMetrics('we left Then we ate', options).sentence_count).toBe(2);
+  });
+
+  it('returns zeros for empty input', () => {
+    expect(computeBasicMetrics('')).toMatchObject({
+      word_count: 0,
+      sentence_count: 0,
+      avg_sentence_length: 0,
+      flesch_reading_ease: 0,
+    });
+  });
+});
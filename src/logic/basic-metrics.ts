@@ -0,0 +1,80 @@
+import { estimatePhonemeCount } from './phonemes';
+import { splitSentences } from './sentence-splitter';
+import { countPolysyllables, countTotalSyllables } from './syllables';
+import { normalizeTranscript } from './transcript-normalizer';
+import { characterCount, countLetters, extractWords } from './text-utils';
+import {
+  automatedReadabilityIndex,
+  colemanLiau,
+  fleschReadingEase,
+  gunningFog,
+  smogGrade,
+  type ReadabilityStats,
+} from './readability';
+import type { Metrics, MetricsOptions } from './metrics-types';
+
+/**
+ * The Metrics fields that need no vocabulary list: counts, syllables, phonemes and every
+ * readability formula except Dale-Chall, which needs its familiar-word list.
+ */
+export type BasicMetrics = Pick<
+  Metrics,
+  | 'word_count'
+  | 'character_count'
+  | 'sentence_count'
+  | 'avg_sentence_length'
+  | 'syllable_count'
+  | 'estimated_phoneme_count'
+  | 'flesch_reading_ease'
+  | 'gunning_fog'
+  | 'smog_grade'
+  | 'coleman_liau'
+  | 'automated_readability_index'
+>;
+
+export type BasicMetricsOptions = Pick<MetricsOptions, 'locale' | 'sentenceSplitMode'>;
+
+export function readabilityStats(
+  textWords: string[],
+  sentenceCount: number,
+  difficultWordCount = 0
+): ReadabilityStats {
+  return {
+    wordCount: textWords.length,
+    sentenceCount,
+    syllableCount: countTotalSyllables(textWords),
+    polysyllableCount: countPolysyllables(textWords),
+    letterCount: countLetters(textWords),
+    difficultWordCount,
+  };
+}
+
+export function calculateBasicReadabilityMetrics(stats: ReadabilityStats) {
+  return {
+    syllable_count: stats.syllableCount,
+    flesch_reading_ease: fleschReadingEase(stats),
+    gunning_fog: gunningFog(stats),
+    smog_grade: smogGrade(stats),
+    coleman_liau: colemanLiau(stats),
+    automated_readability_index: automatedReadabilityIndex(stats),
+  };
+}
+
+/**
+ * Counts and readability without loading any common-word list or vocabulary data, for
+ * embedding where the full computeMetrics is too heavy. Values match computeMetrics with
+ * the same options and the default word count policy; numbers are dropped.
+ */
+export function computeBasicMetrics(text: string, options: BasicMetricsOptions = {}): BasicMetrics {
+  const prepared = normalizeTranscript(text);
+  const textWords = extractWords(prepared, { locale: options.locale });
+  const sentenceCount = splitSentences(prepared, options.sentenceSplitMode).length;
+  return {
+    word_count: textWords.length,
+    character_count: characterCount(text),
+    sentence_count: sentenceCount,
+    avg_sentence_length: sentenceCount > 0 ? textWords.length / sentenceCount : 0,
+    estimated_phoneme_count: estimatePhonemeCount(textWords),
+    ...calculateBasicReadabilityMetrics(readabilityStats(textWords, sentenceCount)),
+  };
+}
@@ -0,0 +1,55 @@
+import { describe, it, expect } from 'vitest';
+import { parseCaptions } from './captions';
+import { computeMetrics } from './metrics-calculator';
+
+const SRT = `1
+00:00:01,000 --> 00:00:03,500
+Good morning, everyone.
+
+2
+00:00:04,000 --> 00:00:07,250
+{\\an8}Today we will <i>practise</i>
+talking about our weekend.
+`;
+
+const VTT = `WEBVTT - Lesson 3
+
+NOTE recorded in class
+
+STYLE
+::cue { color: yellow; }
+
+intro
+00:00.000 --> 00:02.500 align:start position:10%
+<v Teacher>Hello &amp; welcome.</v>
+
+00:03.000 --> 00:06.000
+<c.yellow>What did you</c>
+do <b>yesterday</b>?
+`;
+
+describe('parseCaptions', () => {
+  it('keeps only the spoken text of SRT cues', () => {
+    expect(parseCaptions(SRT, 'srt')).toBe(
+      'Good morning, everyone. Today we will practise talking about our weekend.'
+    );
+  });
+
+  it('skips WebVTT headers, notes and styles and strips cue markup', () => {
+    expect(parseCaptions(VTT, 'vtt')).toBe('Hello & welcome. What did you do yesterday?');
+  });
+
+  it('handles Windows line endings', () => {
+    expect(parseCaptions(SRT.replace(/\n/g, '\r\n'), 'srt')).toBe(parseCaptions(SRT, 'srt'));
+  });
+
+  it('rejects WebVTT without its header', () => {
+    expect(() => parseCaptions(SRT, 'vtt')).toThrow('must start with a WEBVTT header');
+  });
+
+  it('feeds computeMetrics', () => {
+    const metrics = computeMetrics(parseCaptions(SRT, 'srt'));
+    expect(metrics.word_count).toBe(11);
+    expect(metrics.sentence_count).toBe(2);
+  });
+});
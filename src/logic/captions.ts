@@ -0,0 +1,47 @@
+export type CaptionFormat = 'srt' | 'vtt';
+
+const LINE_BREAK_PATTERN = /\r\n?/g;
+const BLOCK_SEPARATOR_PATTERN = /\n\s*\n/;
+const TIMING_ARROW = '-->';
+const VTT_HEADER_PATTERN = /^\uFEFF?WEBVTT(?:[ \t]|$)/;
+const MARKUP_TAG_PATTERN = /<[^>]*>/g;
+/** Positioning overrides such as {\an8} that some SRT files carry. */
+const SRT_OVERRIDE_PATTERN = /\{\\[^}]*\}/g;
+const WHITESPACE_RUN_PATTERN = /\s+/g;
+const ENTITIES: Record<string, string> = {
+  '&amp;': '&',
+  '&lt;': '<',
+  '&gt;': '>',
+  '&nbsp;': ' ',
+  '&lrm;': '',
+  '&rlm;': '',
+};
+const ENTITY_PATTERN = /&(?:amp|lt|gt|nbsp|lrm|rlm);/g;
+
+function cueText(block: string, format: CaptionFormat): string {
+  const lines = block.split('\n');
+  const timing = lines.findIndex((line) => line.includes(TIMING_ARROW));
+  if (timing < 0) return '';
+  let text = lines.slice(timing + 1).join(' ');
+  if (format === 'srt') text = text.replace(SRT_OVERRIDE_PATTERN, '');
+  return text.replace(MARKUP_TAG_PATTERN, '').replace(ENTITY_PATTERN, (entity) => ENTITIES[entity]);
+}
+
+/**
+ * Spoken text of an SRT or WebVTT caption file, ready for computeMetrics. Cue numbers,
+ * identifiers, timings and settings are dropped, as are WebVTT NOTE, STYLE and REGION blocks;
+ * multi-line cues are joined and styling tags (<i>, <c.yellow>, <v Name>) are stripped.
+ * Throws when WebVTT input lacks its WEBVTT header.
+ */
+export function parseCaptions(input: string, format: CaptionFormat): string {
+  const normalized = input.replace(LINE_BREAK_PATTERN, '\n');
+  if (format === 'vtt' && !VTT_HEADER_PATTERN.test(normalized)) {
+    throw new Error('WebVTT captions must start with a WEBVTT header');
+  }
+  return normalized
+    .split(BLOCK_SEPARATOR_PATTERN)
+    .map((block) => cueText(block, format))
+    .join(' ')
+    .replace(WHITESPACE_RUN_PATTERN, ' ')
+    .trim();
+}
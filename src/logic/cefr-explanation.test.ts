@@ -0,0 +1,27 @@
+import { describe, it, expect } from 'vitest';
+import { explainCefr } from './cefr-explanation';
+import { computeMetrics } from './metrics-calculator';
+
+describe('explainCefr', () => {
+  it('names sentence length as the dominant factor for long simple sentences', () => {
+    const metrics = computeMetrics(
+      'I went to the shop and then I went to the park and then I went home with my good friend.'
+    );
+    const explanation = explainCefr(metrics);
+    expect(explanation).toContain('sentence length 40.0 of 40 points');
+    expect(explanation).toContain('so sentence length contributed most');
+    expect(explanation.startsWith(`${metrics.cefr_level}: `)).toBe(true);
+  });
+
+  it('names vocabulary when complex words carry the score', () => {
+    const metrics = computeMetrics(
+      'Bureaucratic inefficiency. Governmental accountability. Institutional transparency.'
+    );
+    expect(explainCefr(metrics)).toContain('so vocabulary complexity contributed most');
+  });
+
+  it('notes when the transcript is too short to be reliable', () => {
+    expect(explainCefr(computeMetrics('I am happy.'))).toContain('too short');
+    expect(explainCefr(computeMetrics(''))).toBe('A1: there are no words to assess.');
+  });
+});
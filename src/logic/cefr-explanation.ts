@@ -0,0 +1,36 @@
+import { DEFAULT_CEFR_THRESHOLDS, type CefrThresholds } from './cefr-thresholds';
+import type { Metrics } from './metrics-types';
+
+type ScoreField = 'cefr_sentence_score' | 'cefr_vocab_score' | 'cefr_grammar_score';
+type WeightField = 'sentenceWeight' | 'vocabWeight' | 'grammarWeight';
+
+const FACTORS: [name: string, score: ScoreField, weight: WeightField][] = [
+  ['sentence length', 'cefr_sentence_score', 'sentenceWeight'],
+  ['vocabulary complexity', 'cefr_vocab_score', 'vocabWeight'],
+  ['grammar clarity', 'cefr_grammar_score', 'grammarWeight'],
+];
+
+const SHORT_TRANSCRIPT_NOTE = ' The transcript is too short for the level to be reliable.';
+
+/**
+ * Plain-language account of the heuristic CEFR level: the points each factor contributed out
+ * of its weight and which one contributed most. Pass the thresholds the metrics were scored
+ * with. Metrics from computeMetricsWithML may carry a model level the points do not explain.
+ */
+export function explainCefr(
+  metrics: Metrics,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): string {
+  if (metrics.word_count === 0) return `${metrics.cefr_level}: there are no words to assess.`;
+  const parts = FACTORS.map(
+    ([name, score, weight]) =>
+      `${name} ${metrics[score].toFixed(1)} of ${thresholds[weight]} points`
+  );
+  const [dominant] = FACTORS.reduce((best, factor) =>
+    metrics[factor[1]] > metrics[best[1]] ? factor : best
+  );
+  const summary =
+    `${metrics.cefr_level}: ${parts[0]}, ${parts[1]} and ${parts[2]}, ` +
+    `so ${dominant} contributed most.`;
+  return metrics.cefr_is_reliable ? summary : summary + SHORT_TRANSCRIPT_NOTE;
+}
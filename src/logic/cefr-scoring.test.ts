@@ -0,0 +1,82 @@
+import { describe, it, expect } from 'vitest';
+import {
+  confidenceForScore,
+  levelForScore,
+  scoreStats,
+  scoreText,
+} from './cefr-scoring';
+import { DEFAULT_CEFR_THRESHOLDS } from './cefr-thresholds';
+
+describe('cefr-scoring', () => {
+  describe('levelForScore', () => {
+    it('maps scores to CEFR bands', () => {
+      expect(levelForScore(0)).toBe('A1');
+      expect(levelForScore(30)).toBe('A2');
+      expect(levelForScore(55)).toBe('B2');
+      expect(levelForScore(100)).toBe('C2');
+    });
+
+    it('uses custom boundaries when given', () => {
+      const thresholds = {
+        ...DEFAULT_CEFR_THRESHOLDS,
+        boundaries: { A1: 20, A2: 40, B1: 60, B2: 80, C1: 90 },
+      };
+      expect(levelForScore(30, thresholds)).toBe('A2');
+      expect(levelForScore(55, thresholds)).toBe('B1');
+      expect(levelForScore(85, thresholds)).toBe('C1');
+    });
+  });
+
+  describe('confidenceForScore', () => {
+    it('is highest at the centre of a band', () => {
+      expect(confidenceForScore(47.5)).toBe(1);
+    });
+
+    it('is lowest on a boundary between bands', () => {
+      expect(confidenceForScore(40)).toBe(0);
+      expect(confidenceForScore(54.9)).toBeLessThan(0.05);
+    });
+
+    it('scales with distance from the nearest boundary', () => {
+      expect(confidenceForScore(43.75)).toBeCloseTo(0.5);
+    });
+
+    it('treats the outer edges of A1 and C2 as secure', () => {
+      expect(confidenceForScore(0)).toBe(1);
+      expect(confidenceForScore(100)).toBe(1);
+    });
+  });
+
+  describe('scoreText', () => {
+    it('scores dense academic text above simple text', () => {
+      const simple = scoreText('I go to the shop. It is big.');
+      const complex = scoreText(
+        'A fundamental understanding of intricate algorithms is absolutely essential for comprehensive software development.'
+      );
+      expect(complex.score).toBeGreaterThan(simple.score);
+    });
+
+    it('returns a level consistent with its score', () => {
+      const result = scoreText('The quick brown fox jumps over the lazy dog.');
+      expect(result.level).toBe(levelForScore(result.score));
+    });
+  });
+
+  describe('scoreStats', () => {
+    const stats = { wordCount: 20, sentenceCount: 2, complexCount: 1, clarityScore: 80 };
+
+    it('splits the score into sentence, vocabulary and grammar points', () => {
+      const result = scoreStats(stats);
+      const { sentence, vocabulary, grammar } = result.components;
+      expect(sentence + vocabulary + grammar).toBeCloseTo(result.score, 10);
+      expect(result.components.vocabulary).toBeCloseTo(20, 10);
+      expect(result.components.grammar).toBeCloseTo(16, 10);
+      expect(scoreStats(stats, DEFAULT_CEFR_THRESHOLDS)).toEqual(result);
+    });
+
+    it('applies tuned caps and weights', () => {
+      const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, maxSentenceLength: 10, grammarWeight: 0 };
+      expect(scoreStats(stats, thresholds).score).toBeCloseTo(60, 10);
+    });
+  });
+});
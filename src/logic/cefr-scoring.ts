@@ -0,0 +1,137 @@
+import { GrammarChecker } from './grammar-checker';
+import { extractWords } from './text-utils';
+import { splitSentences } from './sentence-splitter';
+import { getCommonWords } from './vocabulary';
+import { COMPLEX_DIFFICULTY, wordDifficulty } from './word-difficulty';
+import {
+  BOUNDED_LEVELS,
+  DEFAULT_CEFR_THRESHOLDS,
+  boundaryList,
+  maxScore,
+  type CefrThresholds,
+} from './cefr-thresholds';
+
+export interface ScoringStats {
+  wordCount: number;
+  sentenceCount: number;
+  complexCount: number;
+  clarityScore: number;
+}
+
+/** Points each factor adds to the CEFR score, out of its weight in the thresholds. */
+export interface CefrComponents {
+  sentence: number;
+  vocabulary: number;
+  grammar: number;
+}
+
+export interface TextScore {
+  score: number;
+  level: string;
+  confidence: number;
+  components: CefrComponents;
+}
+
+export interface SentenceLevel {
+  text: string;
+  cefr_level: string;
+  word_count: number;
+}
+
+/** True when the word's difficulty is above COMPLEX_DIFFICULTY. */
+export function isComplexWord(
+  word: string,
+  commonWords: ReadonlySet<string> = getCommonWords()
+): boolean {
+  return wordDifficulty(word, commonWords) > COMPLEX_DIFFICULTY;
+}
+
+export function countComplexWords(
+  textWords: string[],
+  commonWords: ReadonlySet<string> = getCommonWords()
+): number {
+  return textWords.filter((word) => isComplexWord(word, commonWords)).length;
+}
+
+function cefrComponents(
+  avgSentenceLen: number,
+  complexRatio: number,
+  clarityScore: number,
+  thresholds: CefrThresholds
+): CefrComponents {
+  const { maxSentenceLength, maxComplexRatio } = thresholds;
+  return {
+    sentence:
+      (Math.min(avgSentenceLen, maxSentenceLength) / maxSentenceLength) * thresholds.sentenceWeight,
+    vocabulary:
+      (Math.min(complexRatio, maxComplexRatio) / maxComplexRatio) * thresholds.vocabWeight,
+    grammar: (clarityScore / 100) * thresholds.grammarWeight,
+  };
+}
+
+export function levelForScore(
+  totalScore: number,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): string {
+  return BOUNDED_LEVELS.find((level) => totalScore < thresholds.boundaries[level]) ?? 'C2';
+}
+
+function bandEdges(totalScore: number, thresholds: CefrThresholds): [number, number] {
+  const boundaries = boundaryList(thresholds);
+  const upperIndex = boundaries.findIndex((boundary) => totalScore < boundary);
+  if (upperIndex === -1) return [boundaries[boundaries.length - 1], maxScore(thresholds)];
+  const lower = upperIndex === 0 ? 0 : boundaries[upperIndex - 1];
+  return [lower, boundaries[upperIndex]];
+}
+
+/**
+ * How securely a score sits inside its band: 1 at the band centre, 0 on a boundary
+ * shared with a neighbouring band. The outer edges of A1 and C2 are not boundaries.
+ */
+export function confidenceForScore(
+  totalScore: number,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): number {
+  const [lower, upper] = bandEdges(totalScore, thresholds);
+  const distances: number[] = [];
+  if (lower > 0) distances.push(totalScore - lower);
+  if (upper < maxScore(thresholds)) distances.push(upper - totalScore);
+  const halfWidth = (upper - lower) / 2;
+  return Math.min(1, Math.max(0, Math.min(...distances) / halfWidth));
+}
+
+export function scoreStats(
+  stats: ScoringStats,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): TextScore {
+  const { wordCount, sentenceCount, complexCount, clarityScore } = stats;
+  const avgSentenceLen = sentenceCount > 0 ? wordCount / sentenceCount : 0;
+  const complexRatio = wordCount > 0 ? complexCount / wordCount : 0;
+  const components = cefrComponents(avgSentenceLen, complexRatio, clarityScore, thresholds);
+  const score = components.sentence + components.vocabulary + components.grammar;
+  return {
+    score,
+    level: levelForScore(score, thresholds),
+    confidence: confidenceForScore(score, thresholds),
+    components,
+  };
+}
+
+/**
+ * Scores a passage with the sentence-length, vocabulary and grammar heuristic.
+ * No minimum length is enforced, so short passages still receive a band.
+ */
+export function scoreText(
+  text: string,
+  commonWords: ReadonlySet<string> = getCommonWords(),
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): TextScore {
+  const words = extractWords(text);
+  const stats = {
+    wordCount: words.length,
+    sentenceCount: splitSentences(text).length,
+    complexCount: countComplexWords(words, commonWords),
+    clarityScore: GrammarChecker.check(text).clarityScore,
+  };
+  return scoreStats(stats, thresholds);
+}
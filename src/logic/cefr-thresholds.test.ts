@@ -0,0 +1,39 @@
+import { describe, it, expect } from 'vitest';
+import { DEFAULT_CEFR_THRESHOLDS, maxScore, validateThresholds } from './cefr-thresholds';
+
+describe('cefr-thresholds', () => {
+  it('accepts the defaults', () => {
+    expect(() => validateThresholds(DEFAULT_CEFR_THRESHOLDS)).not.toThrow();
+    expect(maxScore(DEFAULT_CEFR_THRESHOLDS)).toBe(100);
+  });
+
+  it('rejects boundaries that do not increase', () => {
+    const boundaries = { ...DEFAULT_CEFR_THRESHOLDS.boundaries, B1: 30 };
+    expect(() => validateThresholds({ ...DEFAULT_CEFR_THRESHOLDS, boundaries })).toThrow(
+      'CEFR boundaries must increase strictly'
+    );
+  });
+
+  it('rejects boundaries at or beyond the maximum score', () => {
+    const boundaries = { ...DEFAULT_CEFR_THRESHOLDS.boundaries, C1: 100 };
+    expect(() => validateThresholds({ ...DEFAULT_CEFR_THRESHOLDS, boundaries })).toThrow();
+  });
+
+  it('rejects negative weights', () => {
+    expect(() => validateThresholds({ ...DEFAULT_CEFR_THRESHOLDS, vocabWeight: -1 })).toThrow(
+      'CEFR weights must be non-negative numbers'
+    );
+  });
+
+  it('rejects a negative minimum word count', () => {
+    expect(() => validateThresholds({ ...DEFAULT_CEFR_THRESHOLDS, minWords: -1 })).toThrow(
+      'CEFR minimum word count must be non-negative'
+    );
+  });
+
+  it('rejects caps that are not positive', () => {
+    expect(() => validateThresholds({ ...DEFAULT_CEFR_THRESHOLDS, maxComplexRatio: 0 })).toThrow(
+      'CEFR caps must be positive numbers'
+    );
+  });
+});
@@ -0,0 +1,61 @@
+export type BoundedLevel = 'A1' | 'A2' | 'B1' | 'B2' | 'C1';
+
+/**
+ * Tunable parts of the heuristic CEFR score. The score is the sum of three parts:
+ * sentence length (avg words per sentence, capped at `maxSentenceLength`, scaled to
+ * `sentenceWeight`), vocabulary (complex-word ratio, capped at `maxComplexRatio`, scaled to
+ * `vocabWeight`) and grammar clarity (scaled to `grammarWeight`). Raising a cap lets longer
+ * or denser speech keep scoring higher; raising a weight makes that part count for more.
+ * `boundaries` are the upper scores of each band below C2, and `minWords` is the fewest words
+ * a transcript needs before its level is considered reliable.
+ */
+export interface CefrThresholds {
+  boundaries: Record<BoundedLevel, number>;
+  sentenceWeight: number;
+  vocabWeight: number;
+  grammarWeight: number;
+  maxSentenceLength: number;
+  maxComplexRatio: number;
+  minWords: number;
+}
+
+export const BOUNDED_LEVELS: readonly BoundedLevel[] = ['A1', 'A2', 'B1', 'B2', 'C1'];
+
+export const DEFAULT_CEFR_THRESHOLDS: CefrThresholds = {
+  boundaries: { A1: 25, A2: 40, B1: 55, B2: 70, C1: 85 },
+  sentenceWeight: 40,
+  vocabWeight: 40,
+  grammarWeight: 20,
+  maxSentenceLength: 12,
+  maxComplexRatio: 0.1,
+  minWords: 10,
+};
+
+export function maxScore(thresholds: CefrThresholds): number {
+  return thresholds.sentenceWeight + thresholds.vocabWeight + thresholds.grammarWeight;
+}
+
+export function boundaryList(thresholds: CefrThresholds): number[] {
+  return BOUNDED_LEVELS.map((level) => thresholds.boundaries[level]);
+}
+
+/**
+ * Throws unless the weights and minimum word count are non-negative, the caps are positive
+ * and the boundaries increase strictly within (0, maximum score).
+ */
+export function validateThresholds(thresholds: CefrThresholds): void {
+  const { sentenceWeight, vocabWeight, grammarWeight } = thresholds;
+  if (![sentenceWeight, vocabWeight, grammarWeight].every((weight) => weight >= 0)) {
+    throw new Error('CEFR weights must be non-negative numbers');
+  }
+  if (!(thresholds.maxSentenceLength > 0 && thresholds.maxComplexRatio > 0)) {
+    throw new Error('CEFR caps must be positive numbers');
+  }
+  if (!(thresholds.minWords >= 0)) {
+    throw new Error('CEFR minimum word count must be non-negative');
+  }
+  const edges = [0, ...boundaryList(thresholds), maxScore(thresholds)];
+  if (!edges.every((edge, i) => i === 0 || edge > edges[i - 1])) {
+    throw new Error(`CEFR boundaries must increase strictly between 0 and ${edges.at(-1)}`);
+  }
+}
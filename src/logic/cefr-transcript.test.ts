@@ -0,0 +1,56 @@
+import { describe, it, expect } from 'vitest';
+import { complexWordSpans, scoreSentences } from './cefr-transcript';
+import { computeMetrics } from './metrics-calculator';
+
+describe('cefr-transcript', () => {
+  describe('scoreSentences', () => {
+    it('returns one level per sentence with word counts', () => {
+      const levels = scoreSentences(['I like cats', 'Dogs are nice too'], {});
+      expect(levels).toHaveLength(2);
+      expect(levels[0]).toMatchObject({ text: 'I like cats', word_count: 3 });
+      expect(levels[1].word_count).toBe(4);
+    });
+
+    it('leaves proper nouns out of sentence complexity', () => {
+      const sentence = ['Yesterday we visited Constantinople'];
+      const [named] = scoreSentences(sentence, { countProperNouns: true });
+      const [plain] = scoreSentences(sentence, {});
+      expect(plain.cefr_level).not.toBe(named.cefr_level);
+    });
+
+    it('counts words the way word_count does', () => {
+      const text = "I can't run 5 km. My mother-in-law ran 10! Isn't that great?";
+      for (const options of [{}, { numberHandling: 'count' as const }]) {
+        const metrics = computeMetrics(text, undefined, options);
+        const counts = metrics.sentence_levels.map((level) => level.word_count);
+        expect(counts.reduce((total, count) => total + count, 0)).toBe(metrics.word_count);
+      }
+    });
+  });
+
+  describe('complexWordSpans', () => {
+    it('excludes adjacent punctuation from the span', () => {
+      const text = 'That was extraordinary!';
+      const spans = complexWordSpans(text);
+      expect(spans).toEqual([[9, 22]]);
+      expect(text.slice(...spans[0])).toBe('extraordinary');
+    });
+
+    it('indexes the original casing and spacing', () => {
+      const text = '  (Extraordinary), bureaucratic.';
+      expect(complexWordSpans(text).map(([start, end]) => text.slice(start, end))).toEqual([
+        'Extraordinary',
+        'bureaucratic',
+      ]);
+    });
+
+    it('maps words found after normalization back to the original text', () => {
+      const text = '  “Bureaucracy’s   extraordinary—phenomenal”';
+      expect(complexWordSpans(text).map(([start, end]) => text.slice(start, end))).toEqual([
+        'Bureaucracy’s',
+        'extraordinary',
+        'phenomenal',
+      ]);
+    });
+  });
+});
@@ -0,0 +1,101 @@
+import { GrammarChecker } from './grammar-checker';
+import {
+  analysisVocabulary,
+  analysisWords,
+  countsProperNouns,
+  resolveCommonWords,
+  splitTokensByPolicy,
+} from './metrics-options';
+import { isAcronym, isLikelyProperNoun } from './proper-nouns';
+import { lowercase, tokenize } from './text-utils';
+import { normalizeTranscriptWithOffsets } from './transcript-normalizer';
+import {
+  countComplexWords,
+  isComplexWord,
+  levelForScore,
+  scoreStats,
+  type SentenceLevel,
+} from './cefr-scoring';
+import { DEFAULT_CEFR_THRESHOLDS } from './cefr-thresholds';
+import type { MetricsOptions } from './metrics-types';
+
+/** Input without words, such as "..." or an emoji, is A1 with no confidence. */
+const EMPTY_TRANSCRIPT_SCORE = {
+  complexCount: 0,
+  reliable: false,
+  level: 'A1',
+  confidence: 0,
+  components: { sentence: 0, vocabulary: 0, grammar: 0 },
+};
+
+/** Scoring inputs for prepared text; proper nouns and acronyms are left out of complexCount. */
+function transcriptStats(
+  text: string,
+  textWords: string[],
+  sentenceCount: number,
+  options: MetricsOptions
+) {
+  const vocabWords = analysisVocabulary(text, options);
+  return {
+    wordCount: textWords.length,
+    sentenceCount,
+    complexCount: countComplexWords(vocabWords, resolveCommonWords(options)),
+    clarityScore: GrammarChecker.check(text).clarityScore,
+  };
+}
+
+/** Heuristic CEFR score of prepared text, with the complex-word count it was based on. */
+export function scoreTranscript(
+  text: string,
+  textWords: string[],
+  sentenceCount: number,
+  options: MetricsOptions
+) {
+  if (textWords.length === 0) return EMPTY_TRANSCRIPT_SCORE;
+  const thresholds = options.thresholds ?? DEFAULT_CEFR_THRESHOLDS;
+  const stats = transcriptStats(text, textWords, sentenceCount, options);
+  const { wordCount, complexCount } = stats;
+  const { score, confidence, components } = scoreStats(stats, thresholds);
+  const reliable = wordCount > 0 && wordCount >= thresholds.minWords;
+  return {
+    complexCount,
+    reliable,
+    level: !reliable && options.forceA1WhenShort ? 'A1' : levelForScore(score, thresholds),
+    confidence: reliable ? confidence : 0,
+    components,
+  };
+}
+
+/**
+ * A level for each prepared sentence, counting its words exactly as word_count does, so the
+ * per-sentence counts add up to it. No minimum length is enforced.
+ */
+export function scoreSentences(sentences: string[], options: MetricsOptions): SentenceLevel[] {
+  const thresholds = options.thresholds ?? DEFAULT_CEFR_THRESHOLDS;
+  return sentences.map((text) => {
+    const words = analysisWords(text, options);
+    const stats = transcriptStats(text, words, 1, options);
+    return { text, cefr_level: scoreStats(stats, thresholds).level, word_count: words.length };
+  });
+}
+
+/**
+ * Offsets of complex words in the original text as [start, end) pairs of UTF-16 indices,
+ * so `text.slice(start, end)` is exactly the word without surrounding punctuation. Words are
+ * found in the normalized transcript, as for the other metrics, and mapped back. Words are
+ * skipped, split by `wordCountPolicy` and lowercased as for complex_words.
+ */
+export function complexWordSpans(text: string, options: MetricsOptions = {}): [number, number][] {
+  const normalized = normalizeTranscriptWithOffsets(text);
+  const { offsets } = normalized;
+  const commonWords = resolveCommonWords(options);
+  const tokens = tokenize(normalized.text)
+    .filter((token) => !isAcronym(token.text))
+    .filter(
+      (token) =>
+        countsProperNouns(options) || !isLikelyProperNoun(normalized.text, token.text, token.start)
+    );
+  return splitTokensByPolicy(tokens, options.wordCountPolicy)
+    .filter((token) => isComplexWord(lowercase(token.text, options.locale), commonWords))
+    .map(({ start, end }): [number, number] => [offsets[start], offsets[end - 1] + 1]);
+}
@@ -0,0 +1,22 @@
+import { describe, it, expect } from 'vitest';
+import { challengingWords } from './challenging-words';
+
+describe('challenging-words', () => {
+  const words = ['kubernetes', 'cat', 'bureaucratic', 'extraordinary', 'bureaucratic'];
+
+  it('lists distinct complex words ordered by syllables then alphabetically', () => {
+    expect(challengingWords(words)).toEqual(['extraordinary', 'bureaucratic', 'kubernetes']);
+  });
+
+  it('skips complex words that are short and have few syllables', () => {
+    expect(challengingWords(['furtive'])).toEqual([]);
+  });
+
+  it('skips common words however long they are', () => {
+    expect(challengingWords(['university'])).toEqual([]);
+  });
+
+  it('caps the list', () => {
+    expect(challengingWords(words, undefined, 1)).toEqual(['extraordinary']);
+  });
+});
@@ -0,0 +1,36 @@
+import { isComplexWord } from './cefr-scoring';
+import { countSyllables } from './syllables';
+import { getCommonWords } from './vocabulary';
+
+const MIN_CHALLENGING_SYLLABLES = 3;
+const MIN_CHALLENGING_LENGTH = 10;
+export const MAX_CHALLENGING_WORDS = 10;
+
+function isChallenging(word: string, commonWords: ReadonlySet<string>): boolean {
+  const isLongOrPolysyllabic =
+    countSyllables(word) >= MIN_CHALLENGING_SYLLABLES ||
+    Array.from(word).length >= MIN_CHALLENGING_LENGTH;
+  return isLongOrPolysyllabic && isComplexWord(word, commonWords);
+}
+
+/** More syllables first, ties alphabetically. */
+export function compareChallenging(a: string, b: string): number {
+  const bySyllables = countSyllables(b) - countSyllables(a);
+  if (bySyllables !== 0) return bySyllables;
+  return a < b ? -1 : a > b ? 1 : 0;
+}
+
+/**
+ * Distinct complex words with 3+ syllables or 10+ characters, the ones a learner is most
+ * likely to stumble over. Ordered by syllable count, ties alphabetically, capped at 10.
+ */
+export function challengingWords(
+  words: string[],
+  commonWords: ReadonlySet<string> = getCommonWords(),
+  limit = MAX_CHALLENGING_WORDS
+): string[] {
+  return [...new Set(words)]
+    .filter((word) => isChallenging(word, commonWords))
+    .sort(compareChallenging)
+    .slice(0, limit);
+}
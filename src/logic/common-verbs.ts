@@ -0,0 +1,226 @@
+/**
+ * Common English verbs: base forms plus irregular past forms and auxiliaries. Regular
+ * inflections ("walks", "walked", "walking") are matched through their stem.
+ */
+export const COMMON_VERB_LIST: readonly string[] = [
+  'be',
+  'am',
+  'is',
+  'are',
+  'was',
+  'were',
+  'been',
+  'being',
+  'have',
+  'has',
+  'had',
+  'having',
+  'do',
+  'does',
+  'did',
+  'done',
+  'doing',
+  'will',
+  'would',
+  'shall',
+  'should',
+  'can',
+  'could',
+  'may',
+  'might',
+  'must',
+  'go',
+  'went',
+  'gone',
+  'come',
+  'came',
+  'get',
+  'got',
+  'gotten',
+  'make',
+  'made',
+  'take',
+  'took',
+  'taken',
+  'see',
+  'saw',
+  'seen',
+  'know',
+  'knew',
+  'known',
+  'think',
+  'thought',
+  'say',
+  'said',
+  'tell',
+  'told',
+  'give',
+  'gave',
+  'given',
+  'find',
+  'found',
+  'want',
+  'need',
+  'like',
+  'love',
+  'hate',
+  'look',
+  'feel',
+  'felt',
+  'become',
+  'became',
+  'leave',
+  'left',
+  'put',
+  'mean',
+  'meant',
+  'keep',
+  'kept',
+  'let',
+  'begin',
+  'began',
+  'begun',
+  'seem',
+  'help',
+  'talk',
+  'turn',
+  'start',
+  'show',
+  'hear',
+  'heard',
+  'play',
+  'run',
+  'ran',
+  'move',
+  'live',
+  'believe',
+  'bring',
+  'brought',
+  'happen',
+  'write',
+  'wrote',
+  'written',
+  'sit',
+  'sat',
+  'stand',
+  'stood',
+  'lose',
+  'lost',
+  'pay',
+  'paid',
+  'meet',
+  'met',
+  'include',
+  'continue',
+  'set',
+  'learn',
+  'change',
+  'lead',
+  'led',
+  'understand',
+  'understood',
+  'watch',
+  'follow',
+  'stop',
+  'create',
+  'speak',
+  'spoke',
+  'spoken',
+  'read',
+  'spend',
+  'spent',
+  'grow',
+  'grew',
+  'grown',
+  'open',
+  'walk',
+  'win',
+  'won',
+  'offer',
+  'remember',
+  'consider',
+  'appear',
+  'buy',
+  'bought',
+  'wait',
+  'serve',
+  'die',
+  'send',
+  'sent',
+  'expect',
+  'build',
+  'built',
+  'stay',
+  'fall',
+  'fell',
+  'fallen',
+  'cut',
+  'reach',
+  'kill',
+  'remain',
+  'suggest',
+  'raise',
+  'pass',
+  'sell',
+  'sold',
+  'require',
+  'report',
+  'decide',
+  'pull',
+  'eat',
+  'ate',
+  'eaten',
+  'drink',
+  'drank',
+  'sleep',
+  'slept',
+  'drive',
+  'drove',
+  'driven',
+  'fly',
+  'flew',
+  'flown',
+  'swim',
+  'swam',
+  'sing',
+  'sang',
+  'wear',
+  'wore',
+  'forget',
+  'forgot',
+  'choose',
+  'chose',
+  'hope',
+  'try',
+  'call',
+  'ask',
+  'work',
+  'use',
+  'visit',
+  'travel',
+  'study',
+  'cook',
+  'clean',
+  'wash',
+  'smile',
+  'laugh',
+  'cry',
+  'listen',
+  'answer',
+  'enjoy',
+  'prefer',
+  'agree',
+  'arrive',
+  'carry',
+  'catch',
+  'caught',
+  'teach',
+  'taught',
+  'fight',
+  'fought',
+  'break',
+  'broke',
+  'broken',
+];
+
+export const COMMON_VERBS = new Set(COMMON_VERB_LIST);
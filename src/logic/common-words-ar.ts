@@ -0,0 +1,84 @@
+/**
+ * A starter list of common Arabic words, written without short-vowel marks, used to
+ * determine vocabulary complexity for Arabic transcripts.
+ */
+export const ARABIC_COMMON_WORD_LIST: readonly string[] = [
+  'في',
+  'من',
+  'على',
+  'إلى',
+  'أن',
+  'هذا',
+  'هذه',
+  'ذلك',
+  'التي',
+  'الذي',
+  'هو',
+  'هي',
+  'أنا',
+  'نحن',
+  'أنت',
+  'هم',
+  'كان',
+  'كانت',
+  'يكون',
+  'لا',
+  'ما',
+  'لم',
+  'لن',
+  'مع',
+  'عن',
+  'كل',
+  'بعد',
+  'قبل',
+  'عند',
+  'بين',
+  'أو',
+  'ثم',
+  'قد',
+  'إذا',
+  'كيف',
+  'لماذا',
+  'متى',
+  'أين',
+  'نعم',
+  'شكرا',
+  'يوم',
+  'اليوم',
+  'بيت',
+  'البيت',
+  'كتاب',
+  'مدرسة',
+  'المدرسة',
+  'ماء',
+  'أكل',
+  'ذهب',
+  'قال',
+  'كبير',
+  'صغير',
+  'جميل',
+  'جيد',
+  'كثير',
+  'قليل',
+  'الله',
+  'مثل',
+  'حتى',
+  'لكن',
+  'أيضا',
+  'جدا',
+  'الآن',
+  'هنا',
+  'هناك',
+  'وقت',
+  'الناس',
+  'رجل',
+  'امرأة',
+  'ولد',
+  'بنت',
+  'عمل',
+  'أريد',
+  'أحب',
+  'صديق',
+];
+
+export const ARABIC_COMMON_WORDS = new Set(ARABIC_COMMON_WORD_LIST);
@@ -0,0 +1,269 @@
+/**
+ * Common German words used to determine vocabulary complexity for German transcripts.
+ * Entries are lowercase like transcript words. German capitalizes every noun, so German
+ * metrics count capitalized mid-sentence words instead of skipping them as proper nouns.
+ */
+export const GERMAN_COMMON_WORD_LIST: readonly string[] = [
+  'der',
+  'die',
+  'das',
+  'den',
+  'dem',
+  'des',
+  'ein',
+  'eine',
+  'einen',
+  'einem',
+  'einer',
+  'eines',
+  'und',
+  'oder',
+  'aber',
+  'denn',
+  'sondern',
+  'nicht',
+  'kein',
+  'keine',
+  'ich',
+  'du',
+  'er',
+  'sie',
+  'es',
+  'wir',
+  'ihr',
+  'mich',
+  'dich',
+  'sich',
+  'uns',
+  'euch',
+  'mir',
+  'dir',
+  'ihm',
+  'ihnen',
+  'mein',
+  'meine',
+  'dein',
+  'deine',
+  'seine',
+  'unser',
+  'ist',
+  'sind',
+  'war',
+  'waren',
+  'bin',
+  'bist',
+  'sein',
+  'haben',
+  'habe',
+  'hast',
+  'hat',
+  'hatte',
+  'werden',
+  'wird',
+  'wurde',
+  'kann',
+  'können',
+  'muss',
+  'müssen',
+  'will',
+  'wollen',
+  'soll',
+  'sollen',
+  'darf',
+  'mag',
+  'möchte',
+  'gehen',
+  'geht',
+  'ging',
+  'kommen',
+  'kommt',
+  'kam',
+  'machen',
+  'macht',
+  'sagen',
+  'sagt',
+  'sehen',
+  'sieht',
+  'geben',
+  'gibt',
+  'nehmen',
+  'finden',
+  'wissen',
+  'weiß',
+  'denken',
+  'glauben',
+  'bleiben',
+  'stehen',
+  'liegen',
+  'sitzen',
+  'leben',
+  'arbeiten',
+  'spielen',
+  'lernen',
+  'essen',
+  'trinken',
+  'wohnen',
+  'schlafen',
+  'kaufen',
+  'lesen',
+  'schreiben',
+  'sprechen',
+  'hören',
+  'fahren',
+  'laufen',
+  'kennen',
+  'heißen',
+  'in',
+  'an',
+  'auf',
+  'aus',
+  'bei',
+  'mit',
+  'nach',
+  'von',
+  'vor',
+  'zu',
+  'über',
+  'unter',
+  'durch',
+  'für',
+  'gegen',
+  'ohne',
+  'um',
+  'bis',
+  'seit',
+  'zwischen',
+  'neben',
+  'hier',
+  'da',
+  'dort',
+  'heute',
+  'morgen',
+  'gestern',
+  'jetzt',
+  'dann',
+  'schon',
+  'noch',
+  'immer',
+  'nie',
+  'oft',
+  'auch',
+  'nur',
+  'sehr',
+  'viel',
+  'viele',
+  'mehr',
+  'wenig',
+  'gut',
+  'schlecht',
+  'groß',
+  'klein',
+  'neu',
+  'alt',
+  'jung',
+  'lang',
+  'kurz',
+  'schön',
+  'heiß',
+  'kalt',
+  'warm',
+  'schnell',
+  'langsam',
+  'richtig',
+  'ja',
+  'nein',
+  'bitte',
+  'danke',
+  'hallo',
+  'tschüss',
+  'was',
+  'wer',
+  'wie',
+  'wo',
+  'wann',
+  'warum',
+  'welche',
+  'welcher',
+  'wenn',
+  'weil',
+  'dass',
+  'ob',
+  'als',
+  'so',
+  'alle',
+  'alles',
+  'etwas',
+  'nichts',
+  'man',
+  'jeder',
+  'jede',
+  'andere',
+  'anderen',
+  'ganz',
+  'gern',
+  'gerne',
+  'vielleicht',
+  'wieder',
+  'zusammen',
+  'mann',
+  'frau',
+  'kind',
+  'kinder',
+  'junge',
+  'mädchen',
+  'freund',
+  'freundin',
+  'familie',
+  'mutter',
+  'vater',
+  'bruder',
+  'schwester',
+  'eltern',
+  'haus',
+  'wohnung',
+  'zimmer',
+  'küche',
+  'schule',
+  'stadt',
+  'land',
+  'straße',
+  'auto',
+  'bus',
+  'zug',
+  'arbeit',
+  'geld',
+  'zeit',
+  'tag',
+  'woche',
+  'monat',
+  'jahr',
+  'nacht',
+  'abend',
+  'uhr',
+  'stunde',
+  'minute',
+  'wasser',
+  'brot',
+  'kaffee',
+  'tee',
+  'milch',
+  'buch',
+  'hund',
+  'katze',
+  'welt',
+  'leute',
+  'mensch',
+  'menschen',
+  'name',
+  'frage',
+  'antwort',
+  'sache',
+  'ende',
+  'anfang',
+  'weg',
+  'hand',
+  'kopf',
+  'auge',
+];
+
+export const GERMAN_COMMON_WORDS = new Set(GERMAN_COMMON_WORD_LIST);
@@ -0,0 +1,198 @@
+/**
+ * Common Spanish words used to determine vocabulary complexity for Spanish transcripts.
+ */
+export const SPANISH_COMMON_WORD_LIST: readonly string[] = [
+  'el',
+  'la',
+  'los',
+  'las',
+  'lo',
+  'de',
+  'del',
+  'al',
+  'que',
+  'y',
+  'o',
+  'a',
+  'en',
+  'un',
+  'una',
+  'unos',
+  'unas',
+  'ser',
+  'es',
+  'son',
+  'era',
+  'fue',
+  'soy',
+  'eres',
+  'somos',
+  'se',
+  'no',
+  'haber',
+  'hay',
+  'ha',
+  'he',
+  'por',
+  'con',
+  'su',
+  'sus',
+  'para',
+  'como',
+  'estar',
+  'está',
+  'están',
+  'estoy',
+  'tener',
+  'tengo',
+  'tiene',
+  'le',
+  'les',
+  'me',
+  'te',
+  'nos',
+  'mi',
+  'mis',
+  'tu',
+  'tus',
+  'todo',
+  'todos',
+  'pero',
+  'más',
+  'hacer',
+  'hace',
+  'poder',
+  'puedo',
+  'puede',
+  'decir',
+  'dice',
+  'este',
+  'esta',
+  'estos',
+  'ese',
+  'esa',
+  'eso',
+  'ir',
+  'voy',
+  'va',
+  'vamos',
+  'otro',
+  'otra',
+  'si',
+  'sí',
+  'ya',
+  'ver',
+  'porque',
+  'dar',
+  'cuando',
+  'él',
+  'ella',
+  'ellos',
+  'ellas',
+  'yo',
+  'usted',
+  'nosotros',
+  'muy',
+  'sin',
+  'vez',
+  'mucho',
+  'mucha',
+  'muchos',
+  'saber',
+  'sé',
+  'qué',
+  'sobre',
+  'mismo',
+  'también',
+  'hasta',
+  'año',
+  'años',
+  'dos',
+  'tres',
+  'querer',
+  'quiero',
+  'entre',
+  'así',
+  'primero',
+  'desde',
+  'grande',
+  'ni',
+  'llegar',
+  'pasar',
+  'tiempo',
+  'día',
+  'días',
+  'uno',
+  'bien',
+  'poco',
+  'deber',
+  'entonces',
+  'poner',
+  'cosa',
+  'cosas',
+  'tanto',
+  'hombre',
+  'mujer',
+  'niño',
+  'parecer',
+  'nuestro',
+  'tan',
+  'donde',
+  'dónde',
+  'ahora',
+  'parte',
+  'después',
+  'antes',
+  'vida',
+  'quedar',
+  'siempre',
+  'nunca',
+  'creer',
+  'hablar',
+  'llevar',
+  'dejar',
+  'nada',
+  'cada',
+  'seguir',
+  'menos',
+  'nuevo',
+  'nueva',
+  'encontrar',
+  'algo',
+  'solo',
+  'casa',
+  'agua',
+  'comer',
+  'beber',
+  'vivir',
+  'trabajo',
+  'trabajar',
+  'amigo',
+  'amiga',
+  'familia',
+  'ciudad',
+  'país',
+  'bueno',
+  'buena',
+  'malo',
+  'hoy',
+  'mañana',
+  'ayer',
+  'aquí',
+  'allí',
+  'gustar',
+  'gusta',
+  'gracias',
+  'hola',
+  'mundo',
+  'noche',
+  'escuela',
+  'libro',
+  'padre',
+  'madre',
+  'hermano',
+  'hermana',
+  'comida',
+];
+
+export const SPANISH_COMMON_WORDS = new Set(SPANISH_COMMON_WORD_LIST);
@@ -0,0 +1,186 @@
+/**
+ * Common French words used to determine vocabulary complexity for French transcripts.
+ */
+export const FRENCH_COMMON_WORD_LIST: readonly string[] = [
+  'le',
+  'la',
+  'les',
+  'de',
+  'des',
+  'du',
+  'un',
+  'une',
+  'et',
+  'à',
+  'au',
+  'aux',
+  'en',
+  'être',
+  'avoir',
+  'que',
+  'qui',
+  'ne',
+  'pas',
+  'ce',
+  'cette',
+  'ces',
+  'il',
+  'elle',
+  'ils',
+  'elles',
+  'je',
+  'tu',
+  'nous',
+  'vous',
+  'on',
+  'se',
+  'sur',
+  'pour',
+  'dans',
+  'par',
+  'plus',
+  'avec',
+  'son',
+  'sa',
+  'ses',
+  'mon',
+  'ma',
+  'mes',
+  'ton',
+  'ta',
+  'tes',
+  'notre',
+  'votre',
+  'leur',
+  'leurs',
+  'faire',
+  'dire',
+  'aller',
+  'voir',
+  'savoir',
+  'pouvoir',
+  'vouloir',
+  'venir',
+  'prendre',
+  'donner',
+  'tout',
+  'tous',
+  'toute',
+  'mais',
+  'ou',
+  'où',
+  'donc',
+  'car',
+  'si',
+  'très',
+  'bien',
+  'aussi',
+  'comme',
+  'quand',
+  'comment',
+  'pourquoi',
+  'oui',
+  'non',
+  'est',
+  'sont',
+  'suis',
+  'es',
+  'sommes',
+  'êtes',
+  'était',
+  'ai',
+  'as',
+  'a',
+  'ont',
+  'avons',
+  'avez',
+  'fait',
+  'va',
+  'vais',
+  'peut',
+  'veux',
+  'jour',
+  'temps',
+  'année',
+  'an',
+  'ans',
+  'homme',
+  'femme',
+  'enfant',
+  'maison',
+  'ville',
+  'pays',
+  'ami',
+  'amie',
+  'famille',
+  'travail',
+  'eau',
+  'chose',
+  'monde',
+  'vie',
+  'grand',
+  'grande',
+  'petit',
+  'petite',
+  'bon',
+  'bonne',
+  'mauvais',
+  'nouveau',
+  'beau',
+  'belle',
+  'autre',
+  'même',
+  'premier',
+  'deux',
+  'trois',
+  'demain',
+  'hier',
+  'ici',
+  'là',
+  'maintenant',
+  'toujours',
+  'jamais',
+  'encore',
+  'déjà',
+  'peu',
+  'beaucoup',
+  'trop',
+  'rien',
+  'quelque',
+  'chaque',
+  'merci',
+  'bonjour',
+  'après',
+  'avant',
+  'depuis',
+  'sans',
+  'sous',
+  'chez',
+  'entre',
+  'contre',
+  'vers',
+  'manger',
+  'boire',
+  'parler',
+  'aimer',
+  'aime',
+  'habiter',
+  'y',
+  'lui',
+  'me',
+  'te',
+  'moi',
+  'toi',
+  'cela',
+  'ça',
+  'école',
+  'livre',
+  'père',
+  'mère',
+  'frère',
+  'soeur',
+  'nuit',
+  'matin',
+];
+
+export const FRENCH_COMMON_WORDS = new Set(FRENCH_COMMON_WORD_LIST);
@@ -0,0 +1,79 @@
+/**
+ * A starter list of common Hindi words used to determine vocabulary complexity for Hindi
+ * transcripts.
+ */
+export const HINDI_COMMON_WORD_LIST: readonly string[] = [
+  'है',
+  'हैं',
+  'था',
+  'थे',
+  'थी',
+  'और',
+  'का',
+  'की',
+  'के',
+  'को',
+  'में',
+  'से',
+  'पर',
+  'यह',
+  'वह',
+  'ये',
+  'वो',
+  'मैं',
+  'हम',
+  'तुम',
+  'आप',
+  'क्या',
+  'नहीं',
+  'भी',
+  'तो',
+  'ही',
+  'एक',
+  'दो',
+  'कुछ',
+  'बहुत',
+  'अच्छा',
+  'घर',
+  'पानी',
+  'खाना',
+  'दिन',
+  'आज',
+  'कल',
+  'लोग',
+  'बात',
+  'काम',
+  'समय',
+  'साल',
+  'जाना',
+  'आना',
+  'करना',
+  'होना',
+  'कहना',
+  'देखना',
+  'मेरा',
+  'मेरी',
+  'उसका',
+  'हमारा',
+  'लेकिन',
+  'अगर',
+  'क्योंकि',
+  'जब',
+  'तब',
+  'यहाँ',
+  'वहाँ',
+  'कैसे',
+  'कौन',
+  'कहाँ',
+  'बड़ा',
+  'छोटा',
+  'नया',
+  'पुराना',
+  'स्कूल',
+  'किताब',
+  'दोस्त',
+  'गया',
+  'गई',
+];
+
+export const HINDI_COMMON_WORDS = new Set(HINDI_COMMON_WORD_LIST);
@@ -0,0 +1,252 @@
+/**
+ * Common Italian words used to determine vocabulary complexity for Italian transcripts.
+ */
+export const ITALIAN_COMMON_WORD_LIST: readonly string[] = [
+  'il',
+  'lo',
+  'la',
+  'i',
+  'gli',
+  'le',
+  'un',
+  'uno',
+  'una',
+  'di',
+  'a',
+  'da',
+  'in',
+  'con',
+  'su',
+  'per',
+  'tra',
+  'fra',
+  'e',
+  'o',
+  'ma',
+  'anche',
+  'non',
+  'che',
+  'chi',
+  'cosa',
+  'come',
+  'dove',
+  'quando',
+  'perché',
+  'se',
+  'io',
+  'tu',
+  'lui',
+  'lei',
+  'noi',
+  'voi',
+  'loro',
+  'mi',
+  'ti',
+  'si',
+  'ci',
+  'vi',
+  'mio',
+  'mia',
+  'tuo',
+  'tua',
+  'suo',
+  'sua',
+  'nostro',
+  'essere',
+  'sono',
+  'sei',
+  'è',
+  'siamo',
+  'siete',
+  'era',
+  'erano',
+  'stato',
+  'avere',
+  'ho',
+  'hai',
+  'ha',
+  'abbiamo',
+  'avete',
+  'hanno',
+  'aveva',
+  'fare',
+  'faccio',
+  'fa',
+  'fatto',
+  'andare',
+  'vado',
+  'va',
+  'andiamo',
+  'venire',
+  'viene',
+  'dire',
+  'dice',
+  'detto',
+  'vedere',
+  'vedo',
+  'potere',
+  'posso',
+  'può',
+  'volere',
+  'voglio',
+  'vuole',
+  'dovere',
+  'devo',
+  'deve',
+  'sapere',
+  'so',
+  'stare',
+  'sto',
+  'sta',
+  'dare',
+  'dà',
+  'parlare',
+  'mangiare',
+  'bere',
+  'dormire',
+  'vivere',
+  'lavorare',
+  'leggere',
+  'scrivere',
+  'capire',
+  'sentire',
+  'pensare',
+  'trovare',
+  'prendere',
+  'mettere',
+  'portare',
+  'comprare',
+  'aprire',
+  'chiudere',
+  'del',
+  'della',
+  'dei',
+  'delle',
+  'al',
+  'alla',
+  'ai',
+  'alle',
+  'dal',
+  'dalla',
+  'nel',
+  'nella',
+  'sul',
+  'sulla',
+  'questo',
+  'questa',
+  'quello',
+  'quella',
+  'molto',
+  'poco',
+  'tanto',
+  'troppo',
+  'più',
+  'meno',
+  'bene',
+  'male',
+  'sempre',
+  'mai',
+  'già',
+  'ancora',
+  'adesso',
+  'oggi',
+  'domani',
+  'ieri',
+  'qui',
+  'qua',
+  'lì',
+  'là',
+  'sì',
+  'no',
+  'grazie',
+  'prego',
+  'ciao',
+  'buongiorno',
+  'buonasera',
+  'tutto',
+  'tutti',
+  'tutte',
+  'niente',
+  'nulla',
+  'qualcosa',
+  'ogni',
+  'altro',
+  'altra',
+  'stesso',
+  'solo',
+  'insieme',
+  'forse',
+  'così',
+  'allora',
+  'poi',
+  'prima',
+  'dopo',
+  'subito',
+  'presto',
+  'tardi',
+  'buono',
+  'buona',
+  'bello',
+  'bella',
+  'grande',
+  'piccolo',
+  'nuovo',
+  'vecchio',
+  'giovane',
+  'lungo',
+  'corto',
+  'caldo',
+  'freddo',
+  'facile',
+  'uomo',
+  'donna',
+  'bambino',
+  'bambina',
+  'ragazzo',
+  'ragazza',
+  'amico',
+  'amica',
+  'famiglia',
+  'madre',
+  'padre',
+  'fratello',
+  'sorella',
+  'figlio',
+  'figlia',
+  'casa',
+  'camera',
+  'cucina',
+  'scuola',
+  'città',
+  'paese',
+  'strada',
+  'macchina',
+  'treno',
+  'lavoro',
+  'soldi',
+  'tempo',
+  'giorno',
+  'settimana',
+  'mese',
+  'anno',
+  'notte',
+  'sera',
+  'ora',
+  'acqua',
+  'pane',
+  'caffè',
+  'vino',
+  'libro',
+  'cane',
+  'gatto',
+  'mondo',
+  'gente',
+  'persona',
+  'nome',
+  'parte',
+  'fine',
+  'mano',
+  'occhio',
+];
+
+export const ITALIAN_COMMON_WORDS = new Set(ITALIAN_COMMON_WORD_LIST);
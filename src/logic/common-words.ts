@@ -1,8 +1,8 @@
 /**
- * Common English words used to determine vocabulary complexity.
- * Words not in this set are considered "complex" for CEFR estimation.
+ * Common English words used to determine vocabulary complexity, split into CEFR bands.
+ * Words not in these lists are considered "complex" for CEFR estimation.
  */
-export const COMMON_WORDS = new Set([
+export const A1_WORD_LIST: readonly string[] = [
   // Core functional words
   'the',
   'be',
@@ -312,6 +312,121 @@ export const COMMON_WORDS = new Set([
   'same',
   'different',
 
+  // Family and people
+  'family',
+  'parent',
+  'father',
+  'mother',
+  'dad',
+  'mom',
+  'son',
+  'daughter',
+  'brother',
+  'sister',
+  'grandfather',
+  'grandmother',
+  'grandpa',
+  'grandma',
+  'husband',
+  'wife',
+  'child',
+  'children',
+  'baby',
+  'boy',
+  'girl',
+  'man',
+  'men',
+  'woman',
+  'women',
+  'person',
+  'friend',
+  'colleague',
+  'neighbor',
+  'guest',
+  'visitor',
+  'boss',
+  'manager',
+  'staff',
+  'employee',
+  'student',
+  'teacher',
+  'professor',
+  'nurse',
+  'officer',
+  'driver',
+  'pilot',
+  'cook',
+  'chef',
+  'waiter',
+  'waitress',
+  'actor',
+  'actress',
+  'artist',
+  'musician',
+  'writer',
+  'singer',
+  'dancer',
+  'athlete',
+  'player',
+  'fan',
+  'audience',
+  'crowd',
+
+  // Colors
+  'color',
+  'red',
+  'blue',
+  'green',
+  'yellow',
+  'purple',
+  'pink',
+  'brown',
+  'black',
+  'white',
+  'gray',
+  'gold',
+  'silver',
+
+  // Body parts
+  'body',
+  'head',
+  'hair',
+  'face',
+  'eye',
+  'nose',
+  'ear',
+  'mouth',
+  'tooth',
+  'teeth',
+  'tongue',
+  'lip',
+  'neck',
+  'shoulder',
+  'arm',
+  'hand',
+  'finger',
+  'thumb',
+  'chest',
+  'stomach',
+  'leg',
+  'knee',
+  'foot',
+  'feet',
+  'toe',
+  'skin',
+  'blood',
+  'heart',
+  'brain',
+  'bone',
+  'muscle',
+
+  // Modal and auxiliary verbs
+  'am',
+  'being',
+
+];
+
+export const A2_WORD_LIST: readonly string[] = [
   // Places
   'home',
   'house',
@@ -545,114 +660,6 @@ export const COMMON_WORDS = new Set([
   'near',
   'far',
 
-  // Family and people
-  'family',
-  'parent',
-  'father',
-  'mother',
-  'dad',
-  'mom',
-  'son',
-  'daughter',
-  'brother',
-  'sister',
-  'grandfather',
-  'grandmother',
-  'grandpa',
-  'grandma',
-  'husband',
-  'wife',
-  'child',
-  'children',
-  'baby',
-  'boy',
-  'girl',
-  'man',
-  'men',
-  'woman',
-  'women',
-  'person',
-  'friend',
-  'colleague',
-  'neighbor',
-  'guest',
-  'visitor',
-  'boss',
-  'manager',
-  'staff',
-  'employee',
-  'student',
-  'teacher',
-  'professor',
-  'nurse',
-  'officer',
-  'driver',
-  'pilot',
-  'cook',
-  'chef',
-  'waiter',
-  'waitress',
-  'actor',
-  'actress',
-  'artist',
-  'musician',
-  'writer',
-  'singer',
-  'dancer',
-  'athlete',
-  'player',
-  'fan',
-  'audience',
-  'crowd',
-
-  // Colors
-  'color',
-  'red',
-  'blue',
-  'green',
-  'yellow',
-  'purple',
-  'pink',
-  'brown',
-  'black',
-  'white',
-  'gray',
-  'gold',
-  'silver',
-
-  // Body parts
-  'body',
-  'head',
-  'hair',
-  'face',
-  'eye',
-  'nose',
-  'ear',
-  'mouth',
-  'tooth',
-  'teeth',
-  'tongue',
-  'lip',
-  'neck',
-  'shoulder',
-  'arm',
-  'hand',
-  'finger',
-  'thumb',
-  'chest',
-  'stomach',
-  'leg',
-  'knee',
-  'foot',
-  'feet',
-  'toe',
-  'skin',
-  'blood',
-  'heart',
-  'brain',
-  'bone',
-  'muscle',
-
   // Health
   'health',
   'sick',
@@ -831,10 +838,6 @@ export const COMMON_WORDS = new Set([
   'noise',
   'voice',
 
-  // Modal and auxiliary verbs
-  'am',
-  'being',
-
   // Informal/casual words
   'hav',
   'havin',
@@ -873,4 +876,8 @@ export const COMMON_WORDS = new Set([
   'cloudy',
   'windy',
   'snowy',
-]);
+];
+
+export const COMMON_WORD_LIST: readonly string[] = [...A1_WORD_LIST, ...A2_WORD_LIST];
+
+export const COMMON_WORDS = new Set(COMMON_WORD_LIST);
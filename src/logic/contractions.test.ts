@@ -0,0 +1,29 @@
+import { describe, it, expect } from 'vitest';
+import { expandContractions } from './contractions';
+
+describe('contractions', () => {
+  it('expands negations including irregular forms', () => {
+    expect(expandContractions("I don't know")).toBe('I do not know');
+    expect(expandContractions("Can't stop, won't stop")).toBe('Can not stop, will not stop');
+    expect(expandContractions("I ain't sure")).toBe('I am not sure');
+  });
+
+  it('expands pronoun contractions', () => {
+    expect(expandContractions("I'm sure we'll see what they've done")).toBe(
+      'I am sure we will see what they have done'
+    );
+    expect(expandContractions("Let's go")).toBe('Let us go');
+  });
+
+  it('expands "it\'s" but leaves the possessive "its" alone', () => {
+    expect(expandContractions("It's wagging its tail")).toBe('It is wagging its tail');
+  });
+
+  it('preserves possessives', () => {
+    expect(expandContractions("John's car")).toBe("John's car");
+  });
+
+  it('accepts typographic apostrophes', () => {
+    expect(expandContractions('you’ve')).toBe('you have');
+  });
+});
@@ -0,0 +1,43 @@
+const NEGATION_PATTERN = /(?<!\p{L})(\p{L}+)n['’]t(?!\p{L})/giu;
+const SUFFIX_PATTERN = /(?<!\p{L})(\p{L}+)['’](re|ve|ll|m|d)(?!\p{L})/giu;
+const IS_PATTERN = /(?<!\p{L})(it|he|she|that|there|here|what|who|where|how)['’]s(?!\p{L})/giu;
+const LETS_PATTERN = /(?<!\p{L})(let)['’]s(?!\p{L})/giu;
+
+const IRREGULAR_NEGATIONS: Record<string, string> = {
+  ca: 'can',
+  wo: 'will',
+  sha: 'shall',
+  ai: 'am',
+};
+
+const SUFFIX_EXPANSIONS: Record<string, string> = {
+  re: 'are',
+  ve: 'have',
+  ll: 'will',
+  m: 'am',
+  d: 'would',
+};
+
+function matchCase(original: string, replacement: string): string {
+  const isCapitalized = original[0] !== original[0].toLowerCase();
+  return isCapitalized ? replacement[0].toUpperCase() + replacement.slice(1) : replacement;
+}
+
+function expandNegation(_match: string, base: string): string {
+  const irregular = IRREGULAR_NEGATIONS[base.toLowerCase()];
+  return `${irregular ? matchCase(base, irregular) : base} not`;
+}
+
+/**
+ * Expands common English contractions ("don't" -> "do not", "I'm" -> "I am").
+ * Only pronoun-style "'s" forms are expanded, so possessives like "John's" are kept.
+ */
+export function expandContractions(text: string): string {
+  return text
+    .replace(NEGATION_PATTERN, expandNegation)
+    .replace(SUFFIX_PATTERN, (_match, base: string, suffix: string) => {
+      return `${base} ${SUFFIX_EXPANSIONS[suffix.toLowerCase()]}`;
+    })
+    .replace(IS_PATTERN, '$1 is')
+    .replace(LETS_PATTERN, '$1 us');
+}
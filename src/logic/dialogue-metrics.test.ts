@@ -0,0 +1,29 @@
+import { describe, it, expect } from 'vitest';
+import { computeDialogueMetrics } from './dialogue-metrics';
+
+describe('computeDialogueMetrics', () => {
+  const dialogue = computeDialogueMetrics([
+    ['tutor', 'How was your weekend?'],
+    ['student', 'It was good. I went to the beach.'],
+    ['tutor', 'Did you swim?'],
+    ['student', 'Yes, twice.'],
+  ]);
+
+  it("merges each speaker's turns", () => {
+    expect(Object.keys(dialogue.speakers)).toEqual(['student', 'tutor']);
+    expect(dialogue.speakers.tutor.word_count).toBe(7);
+    expect(dialogue.speakers.student.word_count).toBe(10);
+    expect(dialogue.speakers.student.sentence_count).toBe(3);
+  });
+
+  it('combines every turn', () => {
+    expect(dialogue.combined.word_count).toBe(17);
+    expect(dialogue.combined.sentence_count).toBe(5);
+  });
+
+  it('handles an empty dialogue', () => {
+    const empty = computeDialogueMetrics([]);
+    expect(empty.speakers).toEqual({});
+    expect(empty.combined.word_count).toBe(0);
+  });
+});
@@ -0,0 +1,36 @@
+import { computeMetrics } from './metrics-calculator';
+import { mergeMetrics } from './metrics-merge';
+import type { Metrics, MetricsOptions } from './metrics-types';
+
+/** A dialogue turn as [speaker, text]. */
+export type DialogueTurn = [speaker: string, text: string];
+
+export interface DialogueMetrics {
+  /** Merged metrics for each speaker's turns, keyed by speaker in sorted order. */
+  speakers: Record<string, Metrics>;
+  /** Merged metrics for every turn. */
+  combined: Metrics;
+}
+
+/**
+ * Analyzes each turn on its own, then merges the turns per speaker and across the dialogue
+ * with mergeMetrics.
+ */
+export function computeDialogueMetrics(
+  turns: DialogueTurn[],
+  options: MetricsOptions = {}
+): DialogueMetrics {
+  const bySpeaker = new Map<string, Metrics[]>();
+  const all = turns.map(([speaker, text]) => {
+    const metrics = computeMetrics(text, undefined, options);
+    bySpeaker.set(speaker, [...(bySpeaker.get(speaker) ?? []), metrics]);
+    return metrics;
+  });
+  const speakers = [...bySpeaker.keys()].sort();
+  return {
+    speakers: Object.fromEntries(
+      speakers.map((speaker) => [speaker, mergeMetrics(bySpeaker.get(speaker) ?? [])])
+    ),
+    combined: mergeMetrics(all),
+  };
+}
@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import { detectFillers, FILLER_WORDS } from './fillers';
+
+describe('fillers', () => {
+  it('includes multi-word phrases', () => {
+    expect(FILLER_WORDS.has('you know')).toBe(true);
+    expect(FILLER_WORDS.has('sort of')).toBe(true);
+  });
+
+  it('counts single-word fillers', () => {
+    const result = detectFillers(['um', 'i', 'went', 'uh', 'home']);
+    expect(result.count).toBe(2);
+    expect(result.tokenCount).toBe(2);
+  });
+
+  it('counts multi-word phrases as one occurrence', () => {
+    const result = detectFillers(['it', 'was', 'you', 'know', 'sort', 'of', 'fun']);
+    expect(result.count).toBe(2);
+    expect(result.tokenCount).toBe(4);
+  });
+
+  it('is case-insensitive', () => {
+    expect(detectFillers(['Um', 'BASICALLY', 'You', 'Know']).count).toBe(3);
+  });
+
+  it('returns zero for speech without fillers', () => {
+    expect(detectFillers(['the', 'dog', 'ran'])).toEqual({ count: 0, tokenCount: 0 });
+  });
+
+  it('handles empty input', () => {
+    expect(detectFillers([])).toEqual({ count: 0, tokenCount: 0 });
+  });
+});
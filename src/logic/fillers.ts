@@ -0,0 +1,56 @@
+/**
+ * Filler words and phrases that pad speech without adding meaning.
+ * Multi-word entries are matched as consecutive tokens. Matching is purely lexical,
+ * so "like" is counted even when used as a verb.
+ */
+export const FILLER_WORDS = new Set([
+  'um',
+  'umm',
+  'uh',
+  'er',
+  'erm',
+  'ah',
+  'hmm',
+  'like',
+  'basically',
+  'you know',
+  'sort of',
+  'kind of',
+  'i mean',
+]);
+
+const MAX_FILLER_PHRASE_LENGTH = 2;
+
+export interface FillerDetection {
+  count: number;
+  tokenCount: number;
+}
+
+function matchFillerAt(words: string[], index: number): number {
+  for (let length = MAX_FILLER_PHRASE_LENGTH; length > 0; length--) {
+    if (index + length > words.length) continue;
+    if (FILLER_WORDS.has(words.slice(index, index + length).join(' '))) return length;
+  }
+  return 0;
+}
+
+/**
+ * Counts filler occurrences in a token list, preferring the longest phrase at each position.
+ * @param words Tokens in spoken order (case-insensitive)
+ * @returns Number of filler occurrences and the number of tokens they cover
+ */
+export function detectFillers(words: string[]): FillerDetection {
+  const lowerWords = words.map((w) => w.toLowerCase());
+  const result: FillerDetection = { count: 0, tokenCount: 0 };
+
+  let index = 0;
+  while (index < lowerWords.length) {
+    const length = matchFillerAt(lowerWords, index);
+    if (length > 0) {
+      result.count++;
+      result.tokenCount += length;
+    }
+    index += Math.max(length, 1);
+  }
+  return result;
+}
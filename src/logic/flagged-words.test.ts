@@ -0,0 +1,17 @@
+import { describe, it, expect } from 'vitest';
+import { countFlaggedWords, FLAGGED_WORDS } from './flagged-words';
+
+describe('flagged-words', () => {
+  it('counts whole-word matches only', () => {
+    expect(countFlaggedWords(['the', 'assistant', 'said', 'damn', 'damn'])).toBe(2);
+  });
+
+  it('ignores words outside the default list', () => {
+    expect(FLAGGED_WORDS.has('class')).toBe(false);
+    expect(countFlaggedWords(['a', 'classic', 'scunthorpe', 'pass'])).toBe(0);
+  });
+
+  it('uses a custom list when given', () => {
+    expect(countFlaggedWords(['silly', 'damn'], new Set(['silly']))).toBe(1);
+  });
+});
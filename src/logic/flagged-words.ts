@@ -0,0 +1,34 @@
+import { normalizeVocabulary } from './vocabulary';
+
+/** Default words flagged as inappropriate for a classroom; schools can supply their own. */
+export const FLAGGED_WORDS: ReadonlySet<string> = normalizeVocabulary([
+  'arse',
+  'ass',
+  'asshole',
+  'bastard',
+  'bitch',
+  'bollocks',
+  'crap',
+  'damn',
+  'dick',
+  'fuck',
+  'fucking',
+  'piss',
+  'shit',
+  'slut',
+  'wanker',
+  'whore',
+]);
+
+/** Counts whole-word matches of the (already normalized) flagged words. */
+export function countFlaggedWords(
+  words: string[],
+  flaggedWords: ReadonlySet<string> = FLAGGED_WORDS
+): number {
+  return words.filter((word) => flaggedWords.has(word)).length;
+}
+
+export function calculateFlaggedMetrics(words: string[], flaggedWords?: ReadonlySet<string>) {
+  const flagged_count = countFlaggedWords(words, flaggedWords);
+  return { contains_flagged_words: flagged_count > 0, flagged_count };
+}
@@ -0,0 +1,42 @@
+import { describe, it, expect } from 'vitest';
+import { calculateFluencyMetrics, fluencyBreakdown, fluencyScore } from './fluency';
+
+const fluent = {
+  word_count: 100,
+  filler_ratio: 0,
+  words_per_minute: 130,
+  repair_count: 0,
+  mtld: 80,
+};
+
+describe('fluency', () => {
+  it('gives full marks to fluent, varied speech at a comfortable pace', () => {
+    expect(calculateFluencyMetrics(fluent)).toEqual({
+      fluency_score: 100,
+      fluency_breakdown: { fillers: 100, pace: 100, repairs: 100, diversity: 100 },
+    });
+  });
+
+  it('penalizes fillers, repairs and pace outside the target band', () => {
+    const breakdown = fluencyBreakdown({
+      ...fluent,
+      filler_ratio: 0.05,
+      words_per_minute: 80,
+      repair_count: 5,
+    });
+    expect(breakdown).toMatchObject({ fillers: 50, pace: 50, repairs: 0 });
+  });
+
+  it('renormalizes over the components that are available', () => {
+    const breakdown = fluencyBreakdown({ ...fluent, words_per_minute: undefined, mtld: 30 });
+    expect(breakdown.pace).toBeUndefined();
+    expect(fluencyScore(breakdown)).toBeCloseTo((0.3 * 100 + 0.2 * 100 + 0.2 * 50) / 0.7);
+  });
+
+  it('scores empty input as 0 with no components', () => {
+    expect(calculateFluencyMetrics({ ...fluent, word_count: 0 })).toEqual({
+      fluency_score: 0,
+      fluency_breakdown: {},
+    });
+  });
+});
@@ -0,0 +1,71 @@
+import type { Metrics } from './metrics-types';
+
+/** Component scores (0-100) behind fluency_score; a component is absent without its data. */
+export interface FluencyBreakdown {
+  fillers?: number;
+  pace?: number;
+  repairs?: number;
+  diversity?: number;
+}
+
+type FluencyInput = Pick<
+  Metrics,
+  'word_count' | 'filler_ratio' | 'words_per_minute' | 'repair_count' | 'mtld'
+>;
+
+/** Relative weight of each component; missing components are left out and the rest rescaled. */
+export const FLUENCY_WEIGHTS: Required<FluencyBreakdown> = {
+  fillers: 0.3,
+  pace: 0.3,
+  repairs: 0.2,
+  diversity: 0.2,
+};
+
+/** Filler share of all words at which the filler component reaches 0. */
+const MAX_FILLER_RATIO = 0.1;
+/** Repairs per word at which the repair component reaches 0. */
+const MAX_REPAIR_RATIO = 0.05;
+/** Speaking rates (words per minute) that earn the full pace score. */
+const TARGET_WPM_RANGE: [number, number] = [110, 160];
+/** How far outside the target range the pace component falls to 0. */
+const PACE_TOLERANCE_WPM = 60;
+/** MTLD earning the full diversity score. */
+export const TARGET_MTLD = 60;
+
+function penalty(value: number, max: number): number {
+  return 100 * (1 - Math.min(value / max, 1));
+}
+
+function paceScore(wpm: number): number {
+  const [low, high] = TARGET_WPM_RANGE;
+  const distance = wpm < low ? low - wpm : Math.max(wpm - high, 0);
+  return penalty(distance, PACE_TOLERANCE_WPM);
+}
+
+export function fluencyBreakdown(input: FluencyInput): FluencyBreakdown {
+  if (input.word_count === 0) return {};
+  return {
+    fillers: penalty(input.filler_ratio, MAX_FILLER_RATIO),
+    pace: input.words_per_minute === undefined ? undefined : paceScore(input.words_per_minute),
+    repairs: penalty(input.repair_count / input.word_count, MAX_REPAIR_RATIO),
+    diversity: Math.min(input.mtld / TARGET_MTLD, 1) * 100,
+  };
+}
+
+/** Weighted mean of the available components, or 0 when none are available. */
+export function fluencyScore(breakdown: FluencyBreakdown): number {
+  let total = 0;
+  let weight = 0;
+  for (const [component, componentWeight] of Object.entries(FLUENCY_WEIGHTS)) {
+    const score = breakdown[component as keyof FluencyBreakdown];
+    if (score === undefined) continue;
+    total += score * componentWeight;
+    weight += componentWeight;
+  }
+  return weight > 0 ? total / weight : 0;
+}
+
+export function calculateFluencyMetrics(input: FluencyInput) {
+  const fluency_breakdown = fluencyBreakdown(input);
+  return { fluency_score: fluencyScore(fluency_breakdown), fluency_breakdown };
+}
@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { countFragments, isFragment } from './fragments';
+
+describe('fragments', () => {
+  describe('isFragment', () => {
+    it('flags sentences without a verb', () => {
+      expect(isFragment('The big red dog.')).toBe(true);
+      expect(isFragment('Lots of fun')).toBe(true);
+    });
+
+    it('accepts sentences with base, inflected or irregular verbs', () => {
+      expect(isFragment('The dog ran.')).toBe(false);
+      expect(isFragment('She walked home')).toBe(false);
+      expect(isFragment('It is cold')).toBe(false);
+    });
+  });
+
+  describe('countFragments', () => {
+    it('counts fragments among split sentences', () => {
+      expect(countFragments(['The dog ran', 'The big red dog', 'Then we left'])).toBe(1);
+      expect(countFragments([])).toBe(0);
+    });
+
+    it('counts nothing for languages without a verb list', () => {
+      expect(countFragments(['El perro grande'], 'es')).toBe(0);
+    });
+  });
+});
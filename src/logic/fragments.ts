@@ -0,0 +1,24 @@
+import { COMMON_VERBS } from './common-verbs';
+import { expandContractions } from './contractions';
+import { stem } from './stemming';
+import { extractWords } from './text-utils';
+import { DEFAULT_LANGUAGE, type Language } from './vocabulary';
+
+function isVerb(word: string): boolean {
+  return COMMON_VERBS.has(word) || COMMON_VERBS.has(stem(word));
+}
+
+/**
+ * Whether an English sentence has no recognizable verb and is likely a fragment ("The big
+ * red dog"). Only verbs in COMMON_VERBS are recognized, and words that can be nouns too
+ * ("walk", "play") count as verbs, so this is an approximation in both directions.
+ * Contractions are expanded first, so "I'm tired" has a verb.
+ */
+export function isFragment(sentence: string): boolean {
+  return !extractWords(expandContractions(sentence)).some(isVerb);
+}
+
+/** Likely fragments among the sentences; only English has a verb list, so others count 0. */
+export function countFragments(sentences: string[], language: Language = DEFAULT_LANGUAGE) {
+  return language === 'en' ? sentences.filter(isFragment).length : 0;
+}
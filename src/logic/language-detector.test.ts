@@ -1,5 +1,5 @@
 import { describe, it, expect } from 'vitest';
-import { detectEnglish } from './language-detector';
+import { LanguageTally, detectEnglish, detectLanguage, detectScript } from './language-detector';
 
 describe('detectEnglish', () => {
   it('identifies simple English sentences', () => {
@@ -53,3 +53,55 @@ describe('detectEnglish', () => {
     expect(result.isLikelyEnglish).toBe(true);
   });
 });
+
+describe('detectScript', () => {
+  it('identifies the dominant script', () => {
+    expect(detectScript('Hello world')).toBe('latin');
+    expect(detectScript('Привет мир')).toBe('cyrillic');
+    expect(detectScript('你好世界')).toBe('cjk');
+    expect(detectScript('مرحبا بالعالم')).toBe('arabic');
+    expect(detectScript('नमस्ते दुनिया')).toBe('devanagari');
+  });
+
+  it('returns other when there are no letters', () => {
+    expect(detectScript('123 ...')).toBe('other');
+  });
+});
+
+describe('detectLanguage', () => {
+  it('picks the language with the most stopword hits', () => {
+    expect(detectLanguage('The cat is on the mat')).toBe('en');
+    expect(detectLanguage('El perro es grande y la casa es blanca')).toBe('es');
+    expect(detectLanguage('Je ne sais pas pour le chat')).toBe('fr');
+    expect(detectLanguage('Ich habe nicht mit der Katze gespielt')).toBe('de');
+    expect(detectLanguage('Non so che cosa fare per questo')).toBe('it');
+  });
+
+  it('maps Arabic script to Arabic and Devanagari to Hindi', () => {
+    expect(detectLanguage('ذهب الولد إلى المدرسة')).toBe('ar');
+    expect(detectLanguage('मैं स्कूल गया')).toBe('hi');
+  });
+
+  it('returns undefined for unsupported scripts or no stopwords', () => {
+    expect(detectLanguage('Привет мир')).toBeUndefined();
+    expect(detectLanguage('xyz qqq')).toBeUndefined();
+  });
+});
+
+describe('LanguageTally', () => {
+  it('matches detectLanguage on everything added', () => {
+    const tally = new LanguageTally();
+    tally.add('The cat ');
+    expect(tally.language('es')).toBe(detectLanguage('The cat es'));
+    tally.add('y la casa ');
+    tally.add('es blanca ');
+    expect(tally.language()).toBe(detectLanguage('The cat y la casa es blanca '));
+    expect(tally.language()).toBe('es');
+  });
+
+  it('does not keep the pending text', () => {
+    const tally = new LanguageTally();
+    expect(tally.language('der die das')).toBe('de');
+    expect(tally.language()).toBeUndefined();
+  });
+});
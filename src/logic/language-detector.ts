@@ -1,4 +1,6 @@
 import { COMMON_WORDS } from './common-words';
+import { extractWords } from './text-utils';
+import type { Language } from './vocabulary';
 
 export interface LanguageDetectionResult {
   isLikelyEnglish: boolean;
@@ -77,3 +79,99 @@ export function detectEnglish(text: string): LanguageDetectionResult {
     validWordCount,
   };
 }
+
+export type Script = 'latin' | 'cyrillic' | 'cjk' | 'arabic' | 'devanagari' | 'other';
+
+const LETTER_PATTERN = /\p{L}/gu;
+const SCRIPT_PATTERNS: [Script, RegExp][] = [
+  ['latin', /\p{Script=Latin}/u],
+  ['cyrillic', /\p{Script=Cyrillic}/u],
+  ['cjk', /[\p{Script=Han}\p{Script=Hiragana}\p{Script=Katakana}\p{Script=Hangul}]/u],
+  ['arabic', /\p{Script=Arabic}/u],
+  ['devanagari', /\p{Script=Devanagari}/u],
+];
+
+/** Non-Latin scripts assumed to be a single supported language. */
+const SCRIPT_LANGUAGES: Partial<Record<Script, Language>> = { arabic: 'ar', devanagari: 'hi' };
+
+const STOPWORDS: [Language, ReadonlySet<string>][] = [
+  ['en', new Set('the and is are was to of in it you that this with for not have'.split(' '))],
+  ['es', new Set('el la los las que de y en es un una por con para no se del lo'.split(' '))],
+  ['fr', new Set('le la les des et est un une que de du dans pour pas je il ne qui'.split(' '))],
+  ['de', new Set('der die das und ist nicht ich ein eine zu mit den von auf sich wir'.split(' '))],
+  ['it', new Set('il lo gli che di è non per sono della nel ma anche questo molto'.split(' '))],
+];
+
+function classifyLetter(letter: string): Script {
+  return SCRIPT_PATTERNS.find(([, pattern]) => pattern.test(letter))?.[0] ?? 'other';
+}
+
+function tallyScripts(text: string, counts: Map<Script, number>): void {
+  for (const letter of text.match(LETTER_PATTERN) ?? []) {
+    const script = classifyLetter(letter);
+    counts.set(script, (counts.get(script) ?? 0) + 1);
+  }
+}
+
+function mostCommonScript(counts: Map<Script, number>): Script {
+  let best: Script = 'other';
+  for (const [script, count] of counts) {
+    if (count > (counts.get(best) ?? 0)) best = script;
+  }
+  return best;
+}
+
+/** Returns the script most letters in the text are written in, or 'other' if none. */
+export function detectScript(text: string): Script {
+  const counts = new Map<Script, number>();
+  tallyScripts(text, counts);
+  return mostCommonScript(counts);
+}
+
+/**
+ * Running letter and stopword counts behind detectLanguage, so streamed text can be detected
+ * without rescanning it. Add text in order, split only at whitespace; language() then matches
+ * detectLanguage on everything added.
+ */
+export class LanguageTally {
+  private scripts = new Map<Script, number>();
+  private stopwordHits = STOPWORDS.map(() => 0);
+
+  add(text: string): void {
+    tallyScripts(text, this.scripts);
+    const words = extractWords(text);
+    STOPWORDS.forEach(([, stopwords], index) => {
+      this.stopwordHits[index] += words.filter((word) => stopwords.has(word)).length;
+    });
+  }
+
+  /** The language of everything added, followed by `pending`, which is not kept. */
+  language(pending = ''): Language | undefined {
+    const tally = new LanguageTally();
+    tally.scripts = new Map(this.scripts);
+    tally.stopwordHits = [...this.stopwordHits];
+    tally.add(pending);
+    return tally.best();
+  }
+
+  private best(): Language | undefined {
+    const script = mostCommonScript(this.scripts);
+    if (script !== 'latin') return SCRIPT_LANGUAGES[script];
+    let best: Language | undefined;
+    let bestHits = 0;
+    for (const [index, [language]] of STOPWORDS.entries()) {
+      const hits = this.stopwordHits[index];
+      if (hits > bestHits) [best, bestHits] = [language, hits];
+    }
+    return best;
+  }
+}
+
+/**
+ * Best-effort guess at which supported vocabulary a transcript uses, from its script and
+ * stopword frequencies. Arabic script is taken as Arabic and Devanagari as Hindi. Returns
+ * undefined for other scripts or when no stopwords match; short inputs are often ambiguous.
+ */
+export function detectLanguage(text: string): Language | undefined {
+  return new LanguageTally().language(text);
+}
@@ -0,0 +1,31 @@
+import { describe, it, expect } from 'vitest';
+import { isKnownLanguageCode, parseLanguageHint, toMetricsLanguage } from './language-hint';
+
+describe('language-hint', () => {
+  it('recognises ISO 639-1 codes Whisper supports', () => {
+    expect(isKnownLanguageCode('es')).toBe(true);
+    expect(isKnownLanguageCode('xx')).toBe(false);
+  });
+
+  it('reads the lang query parameter', () => {
+    expect(parseLanguageHint('?lang=ES')).toBe('es');
+    expect(parseLanguageHint('?foo=1&lang=fr')).toBe('fr');
+  });
+
+  it('returns undefined when the hint is omitted', () => {
+    expect(parseLanguageHint('')).toBeUndefined();
+    expect(parseLanguageHint('?lang=')).toBeUndefined();
+  });
+
+  it('rejects unknown codes', () => {
+    expect(() => parseLanguageHint('?lang=klingon')).toThrow('Unknown language code: klingon');
+  });
+
+  it('maps hints onto the metrics vocabularies', () => {
+    expect(toMetricsLanguage('fr')).toBe('fr');
+    expect(toMetricsLanguage('de')).toBe('de');
+    expect(toMetricsLanguage('hi')).toBe('hi');
+    expect(toMetricsLanguage('ja')).toBeUndefined();
+    expect(toMetricsLanguage(undefined)).toBeUndefined();
+  });
+});
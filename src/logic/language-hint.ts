@@ -0,0 +1,35 @@
+import type { Language } from './vocabulary';
+
+const LANGUAGE_PARAM = 'lang';
+
+/** ISO 639-1 codes that Whisper can be told to transcribe. */
+const WHISPER_LANGUAGE_CODES: ReadonlySet<string> = new Set(
+  (
+    'af am ar as az ba be bg bn bo br bs ca cs cy da de el en es et eu fa fi fo fr gl gu ha he ' +
+    'hi hr ht hu hy id is it ja ka kk km kn ko la lb ln lo lt lv mg mi mk ml mn mr ms mt my ne ' +
+    'nl nn no oc pa pl ps pt ro ru sa sd si sk sl sn so sq sr su sv sw ta te tg th tk tl tr tt ' +
+    'uk ur uz vi yi yo zh'
+  ).split(' ')
+);
+
+const METRICS_LANGUAGES: readonly Language[] = ['en', 'es', 'fr', 'de', 'it', 'ar', 'hi'];
+
+export function isKnownLanguageCode(code: string): boolean {
+  return WHISPER_LANGUAGE_CODES.has(code);
+}
+
+/**
+ * Reads the optional `?lang=` hint from a URL query string. Returns undefined when it is
+ * omitted (auto-detect) and throws for codes Whisper does not know.
+ */
+export function parseLanguageHint(search: string): string | undefined {
+  const code = new URLSearchParams(search).get(LANGUAGE_PARAM)?.trim().toLowerCase();
+  if (!code) return undefined;
+  if (!isKnownLanguageCode(code)) throw new Error(`Unknown language code: ${code}`);
+  return code;
+}
+
+/** Maps a language hint onto a metrics vocabulary, if one exists for it. */
+export function toMetricsLanguage(code: string | undefined): Language | undefined {
+  return METRICS_LANGUAGES.find((language) => language === code);
+}
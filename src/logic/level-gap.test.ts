@@ -0,0 +1,19 @@
+import { describe, it, expect } from 'vitest';
+import { cefrLevelIndex, levelGap } from './level-gap';
+
+describe('level-gap', () => {
+  it('maps A1..C2 to 0..5', () => {
+    expect(cefrLevelIndex('A1')).toBe(0);
+    expect(cefrLevelIndex('c2')).toBe(5);
+  });
+
+  it('is negative below the target and positive above it', () => {
+    expect(levelGap({ cefr_level: 'B1' }, 'B2')).toBe(-1);
+    expect(levelGap({ cefr_level: 'C1' }, 'A2')).toBe(3);
+    expect(levelGap({ cefr_level: 'B2' }, 'B2')).toBe(0);
+  });
+
+  it('rejects an unrecognized target', () => {
+    expect(() => levelGap({ cefr_level: 'B1' }, 'D1')).toThrow('Unknown CEFR level: D1');
+  });
+});
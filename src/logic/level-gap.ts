@@ -0,0 +1,19 @@
+import { BOUNDED_LEVELS } from './cefr-thresholds';
+import type { Metrics } from './metrics-types';
+
+export const CEFR_LEVELS: readonly string[] = [...BOUNDED_LEVELS, 'C2'];
+
+/** Position of a level from A1 = 0 to C2 = 5. Throws for unrecognized levels. */
+export function cefrLevelIndex(level: string): number {
+  const index = CEFR_LEVELS.indexOf(level.trim().toUpperCase());
+  if (index === -1) throw new Error(`Unknown CEFR level: ${level}`);
+  return index;
+}
+
+/**
+ * Signed number of CEFR bands between the computed level and a target level.
+ * Negative means below the target, so -1 reads as "one level below your goal".
+ */
+export function levelGap(metrics: Pick<Metrics, 'cefr_level'>, target: string): number {
+  return cefrLevelIndex(metrics.cefr_level) - cefrLevelIndex(target);
+}
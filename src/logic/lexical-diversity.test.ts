@@ -0,0 +1,101 @@
+import { describe, it, expect } from 'vitest';
+import {
+  lexicalDensity,
+  typeTokenRatio,
+  mtld,
+  uniqueLemmaCount,
+  windowedTypeTokenRatio,
+  wordFrequencies,
+} from './lexical-diversity';
+
+describe('lexical-diversity', () => {
+  describe('typeTokenRatio', () => {
+    it('divides unique words by total words', () => {
+      expect(typeTokenRatio(['the', 'cat', 'saw', 'the', 'dog'])).toBeCloseTo(0.8);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(typeTokenRatio([])).toBe(0);
+    });
+  });
+
+  describe('uniqueLemmaCount', () => {
+    it('folds regular inflections but not irregular forms', () => {
+      expect(uniqueLemmaCount(['walk', 'walks', 'walking', 'walked'])).toBe(1);
+      expect(uniqueLemmaCount(['run', 'ran', 'running', 'runs'])).toBe(2);
+    });
+  });
+
+  describe('windowedTypeTokenRatio', () => {
+    it('reports one ratio per full window and drops the remainder', () => {
+      const words = ['a', 'b', 'c', 'd', 'a', 'a', 'a', 'a', 'z'];
+      expect(windowedTypeTokenRatio(words, 4)).toEqual([1, 0.25]);
+    });
+
+    it('returns a single overall value for transcripts shorter than a window', () => {
+      expect(windowedTypeTokenRatio(['the', 'cat', 'the'])).toEqual([2 / 3]);
+      expect(windowedTypeTokenRatio([])).toEqual([]);
+    });
+
+    it('rejects window sizes below 1', () => {
+      expect(() => windowedTypeTokenRatio(['a'], 0)).toThrow('TTR window size must be at least 1');
+    });
+  });
+
+  describe('mtld', () => {
+    it('counts full factors when the ratio drops below the threshold', () => {
+      expect(mtld(['a', 'a', 'a', 'a'])).toBe(2);
+    });
+
+    it('interpolates a partial factor for short text', () => {
+      expect(mtld(['a', 'b', 'c', 'a'])).toBeCloseTo(4.48, 2);
+    });
+
+    it('returns the word count when no word repeats', () => {
+      expect(mtld(['a', 'b', 'c'])).toBe(3);
+    });
+
+    it('scores varied vocabulary above repetitive vocabulary', () => {
+      const varied = 'the quick brown fox jumps over a lazy dog near my house'.split(' ');
+      const repetitive = 'the dog and the dog and the dog and the dog'.split(' ');
+      expect(mtld(varied)).toBeGreaterThan(mtld(repetitive));
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(mtld([])).toBe(0);
+    });
+  });
+
+  describe('wordFrequencies', () => {
+    it('counts each word', () => {
+      expect(wordFrequencies(['the', 'dog', 'the'])).toEqual({ dog: 1, the: 2 });
+    });
+
+    it('serializes the same regardless of word order', () => {
+      const words = ['zebra', '2', 'apple', '10', 'apple'];
+      expect(JSON.stringify(wordFrequencies(words))).toBe(
+        JSON.stringify(wordFrequencies([...words].reverse()))
+      );
+    });
+
+    it('returns an empty map for empty input', () => {
+      expect(wordFrequencies([])).toEqual({});
+    });
+  });
+
+  describe('lexicalDensity', () => {
+    const common = new Set(['the', 'a', 'is']);
+
+    it('is the share of words outside the common list', () => {
+      expect(lexicalDensity(['the', 'cat', 'is', 'asleep'], common)).toBe(0.5);
+    });
+
+    it('is 1 when no word is common', () => {
+      expect(lexicalDensity(['quantum', 'entanglement'], common)).toBe(1);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(lexicalDensity([], common)).toBe(0);
+    });
+  });
+});
@@ -0,0 +1,93 @@
+import { isCommonWord } from './vocabulary';
+import { stem } from './stemming';
+
+const MTLD_TTR_THRESHOLD = 0.72;
+export const DEFAULT_TTR_WINDOW_SIZE = 50;
+
+/**
+ * Share of distinct words among all words. Sensitive to transcript length.
+ */
+export function typeTokenRatio(words: string[]): number {
+  return words.length > 0 ? new Set(words).size / words.length : 0;
+}
+
+/**
+ * Type-token ratio of consecutive, non-overlapping windows, showing how diversity changes
+ * through a transcript. A trailing partial window is dropped; transcripts shorter than one
+ * window get a single overall value, and empty ones none.
+ */
+export function windowedTypeTokenRatio(
+  words: string[],
+  windowSize = DEFAULT_TTR_WINDOW_SIZE
+): number[] {
+  if (!(windowSize >= 1)) throw new Error('TTR window size must be at least 1');
+  if (words.length === 0) return [];
+  if (words.length < windowSize) return [typeTokenRatio(words)];
+  const ratios: number[] = [];
+  for (let start = 0; start + windowSize <= words.length; start += windowSize) {
+    ratios.push(typeTokenRatio(words.slice(start, start + windowSize)));
+  }
+  return ratios;
+}
+
+/**
+ * Distinct stems, a rough vocabulary size where "walk", "walks" and "walking" count once.
+ * Irregular forms are not lemmatized, so "ran" still counts separately from "run".
+ */
+export function uniqueLemmaCount(words: string[]): number {
+  return new Set(words.map((word) => stem(word))).size;
+}
+
+function mtldPass(words: string[]): number {
+  let factors = 0;
+  let types = new Set<string>();
+  let tokens = 0;
+
+  for (const word of words) {
+    types.add(word);
+    tokens++;
+    if (types.size / tokens <= MTLD_TTR_THRESHOLD) {
+      factors++;
+      types = new Set();
+      tokens = 0;
+    }
+  }
+
+  if (tokens > 0) factors += (1 - types.size / tokens) / (1 - MTLD_TTR_THRESHOLD);
+  return factors > 0 ? words.length / factors : words.length;
+}
+
+/**
+ * Measure of Textual Lexical Diversity (McCarthy & Jarvis, 2010).
+ * Averages forward and backward passes; trailing segments count as partial factors.
+ * Text that never repeats a word has no factors, so the word count is returned.
+ */
+export function mtld(words: string[]): number {
+  if (words.length === 0) return 0;
+  const forward = mtldPass(words);
+  const backward = mtldPass([...words].reverse());
+  return (forward + backward) / 2;
+}
+
+/**
+ * Occurrences of each word. Entries are added alphabetically so the serialized map does not
+ * depend on word order; key order is not promised, as objects list integer-like keys first.
+ */
+export function wordFrequencies(words: string[]): Record<string, number> {
+  const counts = new Map<string, number>();
+  for (const word of words) counts.set(word, (counts.get(word) ?? 0) + 1);
+
+  const sorted = [...counts.entries()].sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0));
+  return Object.fromEntries(sorted);
+}
+
+/**
+ * Approximate lexical density: the share of words outside the common-word list.
+ * Without a POS tagger, common words stand in for function words and everything
+ * else is treated as a content word.
+ */
+export function lexicalDensity(words: string[], commonWords: ReadonlySet<string>): number {
+  if (words.length === 0) return 0;
+  const contentWords = words.filter((word) => !isCommonWord(word, commonWords));
+  return contentWords.length / words.length;
+}
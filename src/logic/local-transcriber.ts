@@ -1,6 +1,7 @@
 import { ITranscriber, TranscriptionResult, TranscriptionWord } from './transcriber';
 import { AudioRecorder } from './audio';
 import { ModelSingleton, calculateWeightedProgress } from './model-loader';
+import { confidenceFields, type WhisperConfidence } from './transcription-confidence';
 
 export {
   subscribeToLoadingState,
@@ -33,7 +34,7 @@ interface WhisperChunk {
   score?: number;
 }
 
-interface WhisperOutput {
+interface WhisperOutput extends WhisperConfidence {
   text?: string;
   chunks?: WhisperChunk[];
 }
@@ -83,6 +84,8 @@ export class LocalTranscriber implements ITranscriber {
   private model: any = null;
   public onProgress?: (msg: string) => void;
   private isTranscriptionActive = false;
+  /** ISO 639-1 code passed to Whisper; auto-detects when undefined. */
+  public language?: string;
 
   /**
    * Get the audio recorder instance for level monitoring
@@ -91,6 +94,12 @@ export class LocalTranscriber implements ITranscriber {
     return this.recorder;
   }
 
+  private transcriptionConfig() {
+    return this.language
+      ? { ...TRANSCRIPTION_CONFIG, language: this.language }
+      : TRANSCRIPTION_CONFIG;
+  }
+
   async start(deviceId?: string): Promise<void> {
     this.isTranscriptionActive = true;
 
@@ -146,7 +155,7 @@ export class LocalTranscriber implements ITranscriber {
 
     try {
       // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      const output: WhisperOutput | any = await this.model(url, TRANSCRIPTION_CONFIG);
+      const output: WhisperOutput | any = await this.model(url, this.transcriptionConfig());
       URL.revokeObjectURL(url);
 
       let text = '';
@@ -164,7 +173,12 @@ export class LocalTranscriber implements ITranscriber {
         text = output[0].text.trim();
       }
 
-      return { text, words, audioBlob: new Blob([audioBlob], { type: 'audio/webm' }) };
+      return {
+        text,
+        words,
+        ...confidenceFields(output ?? {}),
+        audioBlob: new Blob([audioBlob], { type: 'audio/webm' }),
+      };
     } catch (e) {
       console.error('[LocalTranscriber] Transcription error:', e);
       return {
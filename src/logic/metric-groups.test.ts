@@ -0,0 +1,39 @@
+import { describe, it, expect } from 'vitest';
+import { calculateTimeEstimates, calculateWordLengthMetrics } from './metric-groups';
+
+describe('metric-groups', () => {
+  describe('calculateTimeEstimates', () => {
+    it('converts word counts at the reading and speaking rates', () => {
+      const estimates = calculateTimeEstimates(260);
+      expect(estimates.estimated_reading_time_secs).toBeCloseTo(78);
+      expect(estimates.estimated_speaking_time_secs).toBeCloseTo(120);
+    });
+
+    it('returns zero for no words', () => {
+      expect(calculateTimeEstimates(0)).toEqual({
+        estimated_reading_time_secs: 0,
+        estimated_speaking_time_secs: 0,
+      });
+    });
+  });
+
+  describe('calculateWordLengthMetrics', () => {
+    it('reports the longest word and the mean word length', () => {
+      const metrics = calculateWordLengthMetrics(['the', 'elephant', 'sat']);
+      expect(metrics.longest_word).toBe('elephant');
+      expect(metrics.average_word_length).toBeCloseTo(14 / 3);
+    });
+
+    it('picks the first of equally long words', () => {
+      expect(calculateWordLengthMetrics(['cat', 'dog']).longest_word).toBe('cat');
+    });
+
+    it('counts accented letters as single characters', () => {
+      expect(calculateWordLengthMetrics(['café']).average_word_length).toBe(4);
+    });
+
+    it('returns an empty word and zero length for no words', () => {
+      expect(calculateWordLengthMetrics([])).toEqual({ longest_word: '', average_word_length: 0 });
+    });
+  });
+});
@@ -0,0 +1,95 @@
+import { characterCount } from './text-utils';
+import { daleChall } from './readability';
+import { calculateBasicReadabilityMetrics, readabilityStats } from './basic-metrics';
+import { detectFillers } from './fillers';
+import { detectRepairs } from './repetition';
+import { bandCounts } from './vocabulary-bands';
+import type { Language } from './vocabulary';
+import {
+  lexicalDensity,
+  typeTokenRatio,
+  mtld,
+  uniqueLemmaCount,
+  wordFrequencies,
+} from './lexical-diversity';
+import type { WordScores } from './metrics-types';
+
+const PRONUNCIATION_SCALE = 100;
+const SECONDS_PER_MINUTE = 60;
+/** Typical silent reading rate for adult readers, in words per minute. */
+export const READING_WPM = 200;
+/** Typical conversational speaking rate, in words per minute. */
+export const SPEAKING_WPM = 130;
+
+const utf8Encoder = new TextEncoder();
+
+export function countBytes(text: string): number {
+  return utf8Encoder.encode(text).length;
+}
+
+export function calculatePronunciationScore(words?: WordScores): number {
+  if (!words || words.length === 0) return 0;
+  const totalScore = words.reduce((acc, w) => acc + (w.score || 0), 0);
+  return Math.round((totalScore / words.length) * PRONUNCIATION_SCALE);
+}
+
+export function calculateWordsPerMinute(
+  wordCount: number,
+  durationSecs?: number
+): number | undefined {
+  if (!durationSecs || durationSecs <= 0) return undefined;
+  return wordCount / (durationSecs / SECONDS_PER_MINUTE);
+}
+
+/** Seconds needed to read or say `wordCount` words at READING_WPM and SPEAKING_WPM. */
+export function calculateTimeEstimates(wordCount: number) {
+  return {
+    estimated_reading_time_secs: (wordCount / READING_WPM) * SECONDS_PER_MINUTE,
+    estimated_speaking_time_secs: (wordCount / SPEAKING_WPM) * SECONDS_PER_MINUTE,
+  };
+}
+
+export function calculateLexicalMetrics(
+  textWords: string[],
+  commonWords: ReadonlySet<string>,
+  language?: Language
+) {
+  const fillers = detectFillers(textWords);
+  const repairs = detectRepairs(textWords);
+  const uniqueWords = [...new Set(textWords)].sort();
+  const wordCount = textWords.length;
+  return {
+    unique_words: uniqueWords.length,
+    unique_word_list: uniqueWords,
+    unique_lemmas: uniqueLemmaCount(uniqueWords),
+    filler_count: fillers.count,
+    filler_ratio: wordCount > 0 ? fillers.tokenCount / wordCount : 0,
+    repair_count: repairs.count,
+    repaired_tokens: repairs.tokens,
+    type_token_ratio: typeTokenRatio(textWords),
+    mtld: mtld(textWords),
+    word_frequencies: wordFrequencies(textWords),
+    lexical_density: lexicalDensity(textWords, commonWords),
+    band_counts: bandCounts(textWords, language),
+  };
+}
+
+/** Longest word (first on ties) and mean word length, both in code points. */
+export function calculateWordLengthMetrics(textWords: string[]) {
+  const lengths = textWords.map(characterCount);
+  const longestIndex = lengths.reduce((best, length, i) => (length > lengths[best] ? i : best), 0);
+  const totalLength = lengths.reduce((total, length) => total + length, 0);
+  return {
+    longest_word: textWords[longestIndex] ?? '',
+    average_word_length: textWords.length > 0 ? totalLength / textWords.length : 0,
+  };
+}
+
+export function calculateReadabilityMetrics(
+  textWords: string[],
+  sentenceCount: number,
+  difficultWordCount: number
+) {
+  const stats = readabilityStats(textWords, sentenceCount, difficultWordCount);
+  return { ...calculateBasicReadabilityMetrics(stats), dale_chall: daleChall(stats) };
+}
@@ -0,0 +1,81 @@
+import { describe, it, expect } from 'vitest';
+import { MetricsAccumulator } from './metrics-accumulator';
+import { computeMetrics } from './metrics-calculator';
+
+function accumulate(chunks: string[]): MetricsAccumulator {
+  const accumulator = new MetricsAccumulator();
+  chunks.forEach((chunk) => accumulator.push(chunk));
+  return accumulator;
+}
+
+describe('MetricsAccumulator', () => {
+  const chunks = ['I don', "'t think the uni", 'versity is ', 'open. It is', ' closed today.'];
+  const text = chunks.join('');
+
+  it('keeps running counts that match computeMetrics', () => {
+    const accumulator = accumulate(chunks);
+    const metrics = computeMetrics(text);
+    expect(accumulator.wordCount).toBe(metrics.word_count);
+    expect(accumulator.characterCount).toBe(metrics.character_count);
+    expect(accumulator.uniqueWordCount).toBe(metrics.unique_words);
+    expect(accumulator.complexWordCount).toBe(metrics.complex_words);
+  });
+
+  it('skips proper nouns split from their sentence by a chunk boundary', () => {
+    const text = 'Yesterday I visited Constantinople.';
+    const accumulator = accumulate(['Yesterday I visited ', 'Constantinople.']);
+    expect(accumulator.complexWordCount).toBe(computeMetrics(text).complex_words);
+  });
+
+  it('uses the detected language for running counts', () => {
+    const options = { autoDetectLanguage: true };
+    const german = [
+      'Gestern haben ',
+      'wir die Geburtstags',
+      'feier vorbereitet. Die Kinder haben ',
+      'Luftballons aufgeblasen.',
+    ];
+    const accumulator = new MetricsAccumulator(options);
+    german.forEach((chunk) => accumulator.push(chunk));
+    const metrics = computeMetrics(german.join(''), undefined, options);
+    expect(accumulator.wordCount).toBe(metrics.word_count);
+    expect(accumulator.uniqueWordCount).toBe(metrics.unique_words);
+    expect(accumulator.complexWordCount).toBe(metrics.complex_words);
+    expect(metrics.complex_words).toBeGreaterThan(0);
+  });
+
+  it('stops counting at maxWords like finish', () => {
+    const options = { maxWords: 4.5 };
+    const accumulator = new MetricsAccumulator(options);
+    chunks.forEach((chunk) => accumulator.push(chunk));
+    const metrics = accumulator.finish();
+    expect(metrics).toEqual(computeMetrics(text, undefined, options));
+    expect(accumulator.wordCount).toBe(metrics.word_count);
+    expect(accumulator.characterCount).toBe(metrics.character_count);
+    expect(accumulator.uniqueWordCount).toBe(metrics.unique_words);
+    expect(accumulator.complexWordCount).toBe(metrics.complex_words);
+  });
+
+  it('counts words under the word count policy', () => {
+    const options = { wordCountPolicy: 'split-hyphens' as const };
+    const accumulator = new MetricsAccumulator(options);
+    ['My mother-in', '-law is well-', 'known.'].forEach((chunk) => accumulator.push(chunk));
+    expect(accumulator.wordCount).toBe(7);
+    expect(accumulator.finish().word_count).toBe(7);
+  });
+
+  it('does not count a word split across chunks twice', () => {
+    const accumulator = accumulate(['hel', 'lo wor', 'ld']);
+    expect(accumulator.wordCount).toBe(2);
+  });
+
+  it('produces the same metrics as the concatenated input', () => {
+    expect(accumulate(chunks).finish()).toEqual(computeMetrics(text));
+  });
+
+  it('reports empty metrics when nothing was pushed', () => {
+    const accumulator = new MetricsAccumulator();
+    expect(accumulator.wordCount).toBe(0);
+    expect(accumulator.finish()).toEqual(computeMetrics(''));
+  });
+});
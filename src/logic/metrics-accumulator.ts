@@ -0,0 +1,135 @@
+import { characterCount } from './text-utils';
+import { LanguageTally } from './language-detector';
+import { countComplexWords } from './cefr-scoring';
+import { isSentenceInitial } from './proper-nouns';
+import { computeMetrics } from './metrics-calculator';
+import {
+  analysisVocabulary,
+  analysisWords,
+  capWords,
+  prepareText,
+  resolveCommonWords,
+  resolveOptions,
+} from './metrics-options';
+import type { Metrics, MetricsOptions, WordScores } from './metrics-types';
+
+const LAST_WHITESPACE_PATTERN = /\s(?=\S*$)/u;
+
+interface RunningCounts {
+  words: number;
+  complex: number;
+}
+
+/**
+ * Accumulates streamed transcript chunks, keeping word, character, unique-word and
+ * complex-word counts up to date without re-analyzing the whole buffer. Chunks are
+ * concatenated as-is, so include any separating whitespace in the chunks themselves.
+ * With `autoDetectLanguage`, running letter and stopword tallies track the language of
+ * everything pushed so far, and the running counts are redone whenever it changes. Once
+ * `maxWords` is exceeded the counts are redone for the capped text and later chunks no
+ * longer change them.
+ */
+export class MetricsAccumulator {
+  private chunks: string[] = [];
+  private settledText = '';
+  private pending = '';
+  private settled: RunningCounts = { words: 0, complex: 0 };
+  private uniqueWords = new Set<string>();
+  private characters = 0;
+  private startsSentence = true;
+  private capped = false;
+  private languageTally = new LanguageTally();
+  private options: MetricsOptions;
+
+  constructor(private readonly metricsOptions: MetricsOptions = {}) {
+    this.options = resolveOptions('', metricsOptions);
+  }
+
+  push(chunk: string): void {
+    this.chunks.push(chunk);
+    if (this.capped) return;
+    this.characters += characterCount(chunk);
+    const buffer = this.pending + chunk;
+    const cut = buffer.search(LAST_WHITESPACE_PATTERN);
+    const segment = cut < 0 ? '' : buffer.slice(0, cut + 1);
+    this.pending = buffer.slice(cut + 1);
+    this.detectLanguage(segment);
+    if (segment) this.settle(segment);
+    this.applyCap();
+  }
+
+  get wordCount(): number {
+    return this.settled.words + this.countPending().words;
+  }
+
+  get complexWordCount(): number {
+    return this.settled.complex + this.countPending().complex;
+  }
+
+  get uniqueWordCount(): number {
+    const unique = new Set(this.uniqueWords);
+    this.countSegment(this.pending, unique);
+    return unique.size;
+  }
+
+  get characterCount(): number {
+    return this.characters;
+  }
+
+  /** Computes the full metrics, including sentence-level ones, for everything pushed. */
+  finish(words?: WordScores): Metrics {
+    return computeMetrics(this.chunks.join(''), words, this.metricsOptions);
+  }
+
+  private settle(segment: string): void {
+    const counts = this.countSegment(segment, this.uniqueWords);
+    this.settled.words += counts.words;
+    this.settled.complex += counts.complex;
+    this.startsSentence = isSentenceInitial(segment, segment.length, this.startsSentence);
+    this.settledText += segment;
+  }
+
+  /** Tallies the newly settled segment and redoes the counts if the language changed. */
+  private detectLanguage(segment: string): void {
+    if (this.metricsOptions.language || !this.metricsOptions.autoDetectLanguage) return;
+    this.languageTally.add(segment);
+    const language = this.languageTally.language(this.pending);
+    if (language === this.options.language) return;
+    this.options = { ...this.metricsOptions, language };
+    this.recount(this.settledText);
+  }
+
+  private applyCap(): void {
+    const { maxWords } = this.metricsOptions;
+    if (maxWords === undefined || this.wordCount <= maxWords) return;
+    const { text } = capWords(this.chunks.join(''), this.options);
+    this.capped = true;
+    this.characters = characterCount(text);
+    this.pending = '';
+    this.recount(text);
+  }
+
+  /** Drops the running counts and settles `text` from the start of the transcript. */
+  private recount(text: string): void {
+    this.settledText = '';
+    this.settled = { words: 0, complex: 0 };
+    this.uniqueWords = new Set();
+    this.startsSentence = true;
+    if (text) this.settle(text);
+  }
+
+  private countPending(): RunningCounts {
+    return this.countSegment(this.pending, new Set());
+  }
+
+  private countSegment(segment: string, unique: Set<string>): RunningCounts {
+    const prepared = prepareText(segment, this.options);
+    const segmentWords = analysisWords(prepared, this.options);
+    const vocabWords = analysisVocabulary(prepared, this.options, this.startsSentence);
+    segmentWords.forEach((word) => unique.add(word));
+    return {
+      words: segmentWords.length,
+      complex: countComplexWords(vocabWords, resolveCommonWords(this.options)),
+    };
+  }
+}
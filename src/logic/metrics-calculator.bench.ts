@@ -0,0 +1,39 @@
+import { bench, describe } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+import { computeCEFRLevel, computeMetricsFromBytes } from './metrics-variants';
+
+const SENTENCE =
+  'Yesterday I went to the market with my sister, and we bought some fresh bread and cheese. ';
+
+const TRANSCRIPTS = {
+  small: SENTENCE,
+  medium: SENTENCE.repeat(20),
+  large: SENTENCE.repeat(500),
+};
+
+/** Exactly 2000 words, for comparing the CEFR-only path with full metrics. */
+const TWO_THOUSAND_WORDS = SENTENCE.repeat(120).split(' ').slice(0, 2000).join(' ');
+
+for (const [size, text] of Object.entries(TRANSCRIPTS)) {
+  const bytes = new TextEncoder().encode(text);
+
+  describe(`${size} transcript`, () => {
+    bench('computeMetrics', () => {
+      computeMetrics(text);
+    });
+
+    bench('computeMetricsFromBytes', () => {
+      computeMetricsFromBytes(bytes);
+    });
+  });
+}
+
+describe('2000-word transcript', () => {
+  bench('computeMetrics', () => {
+    computeMetrics(TWO_THOUSAND_WORDS);
+  });
+
+  bench('computeCEFRLevel', () => {
+    computeCEFRLevel(TWO_THOUSAND_WORDS);
+  });
+});
@@ -0,0 +1,124 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+
+describe('metrics-calculator', () => {
+  describe('computeMetrics CEFR scoring', () => {
+    it('calculates low CEFR (A1/A2) for simple text', () => {
+      const text = 'I go to the shop. It is big. I see a cat. The dog is nice.';
+      const metrics = computeMetrics(text);
+      expect(['A1', 'A2']).toContain(metrics.cefr_level);
+    });
+
+    it('calculates high CEFR (B2/C1) for complex text', () => {
+      const text =
+        'A fundamental understanding of intricate algorithms is absolutely essential for comprehensive software development.';
+      const metrics = computeMetrics(text);
+
+      expect(['B2', 'C1', 'C2']).toContain(metrics.cefr_level);
+    });
+
+    it('identifies complex words correctly', () => {
+      const text = 'The xylophone is loud';
+      const metrics = computeMetrics(text);
+      expect(metrics.complex_words).toBe(0);
+    });
+
+    it('requires uncommon AND (long OR academic suffix) for complexity', () => {
+      const text = 'important university information';
+      const metrics = computeMetrics(text);
+      expect(metrics.complex_words).toBe(1);
+    });
+
+    it('reports per-sentence CEFR levels with original casing', () => {
+      const metrics = computeMetrics(
+        '  I like cats.   Comprehensive infrastructure modernisation matters! '
+      );
+      expect(metrics.sentence_levels.map((s) => s.text)).toEqual([
+        'I like cats',
+        'Comprehensive infrastructure modernisation matters',
+      ]);
+      expect(metrics.sentence_levels[0].word_count).toBe(3);
+    });
+
+    it('reports a CEFR confidence between 0 and 1', () => {
+      const metrics = computeMetrics(
+        'I think that learning a new language is very interesting but also quite difficult.'
+      );
+      expect(metrics.cefr_confidence).toBeGreaterThanOrEqual(0);
+      expect(metrics.cefr_confidence).toBeLessThanOrEqual(1);
+    });
+
+    it('reports zero CEFR confidence for text too short to assess', () => {
+      expect(computeMetrics('Hello there').cefr_confidence).toBe(0);
+    });
+
+    it('scores simple German as less complex than jargon-heavy German', () => {
+      const options = { language: 'de' as const };
+      const simple = computeMetrics(
+        'Ich habe heute ein Haus gesehen. Das Haus ist schön.',
+        undefined,
+        options
+      );
+      const jargon = computeMetrics(
+        'Die Quantenchromodynamik beschreibt Wechselwirkungen zwischen Gluonen mittels Eichfeldtheorie.',
+        undefined,
+        options
+      );
+      expect(simple.complex_words).toBe(0);
+      expect(jargon.complex_words).toBeGreaterThanOrEqual(3);
+    });
+
+    it('counts acronyms and leaves them out of complex words', () => {
+      const metrics = computeMetrics('NASA hired me. I work at NASA and the FBI.');
+      expect(metrics.acronym_count).toBe(3);
+      expect(metrics.complex_words).toBe(0);
+      expect(metrics.complex_word_spans).toEqual([]);
+    });
+
+    it('ignores likely proper nouns in the complex count by default', () => {
+      const text = 'Yesterday I visited Constantinople with Bartholomew';
+      expect(computeMetrics(text).complex_words).toBe(0);
+      expect(computeMetrics(text).complex_word_spans).toEqual([]);
+      expect(computeMetrics(text, undefined, { countProperNouns: true }).complex_words).toBe(2);
+    });
+
+    it('still judges a capitalized sentence-initial word', () => {
+      expect(computeMetrics('Bureaucratic delays.').complex_words).toBe(1);
+    });
+
+    it('incorporates grammar clarity bonus for high quality text', () => {
+      const text = 'The quick brown fox jumps over the lazy dog.';
+      const metrics = computeMetrics(text, undefined, { forceA1WhenShort: true });
+
+      expect(metrics.cefr_level).toBeDefined();
+      expect(['A1', 'A2']).toContain(metrics.cefr_level);
+    });
+
+    it('reproduces the output recorded before the thresholds became tunable', () => {
+      const golden =
+        'My grandmother grew vegetables in her garden every summer. ' +
+        'We helped her carry water from the river. ' +
+        'Those afternoons taught me patience and responsibility.';
+      expect(computeMetrics(golden)).toMatchObject({
+        word_count: 24,
+        sentence_count: 3,
+        complex_words: 2,
+        cefr_level: 'C1',
+        cefr_confidence: 0.6666666666666666,
+        sentence_levels: [
+          {
+            text: 'My grandmother grew vegetables in her garden every summer',
+            cefr_level: 'B1',
+            word_count: 9,
+          },
+          { text: 'We helped her carry water from the river', cefr_level: 'B1', word_count: 8 },
+          {
+            text: 'Those afternoons taught me patience and responsibility',
+            cefr_level: 'C1',
+            word_count: 7,
+          },
+        ],
+      });
+    });
+  });
+});
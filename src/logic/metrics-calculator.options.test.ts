@@ -0,0 +1,145 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+import { DEFAULT_CEFR_THRESHOLDS } from './cefr-thresholds';
+
+describe('metrics-calculator', () => {
+  describe('computeMetrics options', () => {
+    it('does not count abbreviations and decimals as sentence breaks', () => {
+      expect(computeMetrics('Mr. Jones paid $3.50.').sentence_count).toBe(1);
+    });
+
+    it('treats unpunctuated speech as a single sentence', () => {
+      const metrics = computeMetrics('i went to the store and bought some milk');
+      expect(metrics.sentence_count).toBe(1);
+      expect(metrics.avg_sentence_length).toBe(9);
+    });
+
+    it('splits unpunctuated speech heuristically when asked', () => {
+      const text = 'I went to the store I bought milk';
+      const options = { sentenceSplitMode: 'heuristic' as const };
+      expect(computeMetrics(text, undefined, options).sentence_count).toBe(2);
+    });
+
+    it('keeps accented letters inside words', () => {
+      const metrics = computeMetrics('naïve café');
+      expect(metrics.word_count).toBe(2);
+    });
+
+    it('uses the common-word list for the requested language', () => {
+      const text = 'Je pars maintenant avec ma famille';
+      expect(computeMetrics(text, undefined, { language: 'fr' }).complex_words).toBe(0);
+      expect(computeMetrics(text).complex_words).toBe(1);
+    });
+
+    it('auto-detects the language when asked and none is given', () => {
+      const text = 'Je pars maintenant avec ma famille';
+      expect(computeMetrics(text, undefined, { autoDetectLanguage: true }).complex_words).toBe(0);
+    });
+
+    it('segments Japanese by character and splits on full-width stops', () => {
+      const metrics = computeMetrics('私は学生です。日本語を勉強します。');
+      expect(metrics.word_count).toBe(15);
+      expect(metrics.sentence_count).toBe(2);
+    });
+
+    it('reports windowed type-token ratios with a configurable window', () => {
+      const text = 'one two three four one one one one';
+      expect(computeMetrics(text).windowed_ttr).toEqual([0.5]);
+      expect(computeMetrics(text, undefined, { ttrWindowSize: 4 }).windowed_ttr).toEqual([1, 0.25]);
+    });
+
+    it('rejects invalid thresholds passed as options', () => {
+      const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, maxSentenceLength: 0 };
+      expect(() => computeMetrics('Hello there.', undefined, { thresholds })).toThrow();
+      const boundaries = { A1: 20, A2: 20, B1: 55, B2: 70, C1: 85 };
+      const equal = { ...DEFAULT_CEFR_THRESHOLDS, boundaries };
+      expect(() => computeMetrics('Hello there.', undefined, { thresholds: equal })).toThrow();
+    });
+
+    it('analyzes only the first maxWords words and flags the cut', () => {
+      const metrics = computeMetrics('The cat sat. The dog ran away.', undefined, { maxWords: 4 });
+      expect(metrics.truncated).toBe(true);
+      expect(metrics.word_count).toBe(4);
+      expect(metrics.sentence_count).toBe(2);
+      expect(computeMetrics('The cat sat.', undefined, { maxWords: 4 }).truncated).toBe(false);
+    });
+
+    it('matches Turkish vocabulary when lowercasing with the Turkish locale', () => {
+      const options = { locale: 'tr', commonWords: new Set(['istanbul', 'ırmak']) };
+      const metrics = computeMetrics('İstanbul IRMAK', undefined, options);
+      expect(metrics.unique_word_list).toEqual(['istanbul', 'ırmak']);
+      expect(computeMetrics('İstanbul').unique_word_list).toEqual(['i\u0307stanbul']);
+    });
+
+    it('judges complex words and their spans with the Turkish locale', () => {
+      const commonWords = new Set(['ilköğretim', 'güzel']);
+      const turkish = computeMetrics('İlköğretim güzel.', undefined, { locale: 'tr', commonWords });
+      expect(turkish).toMatchObject({ complex_words: 0, complex_word_spans: [] });
+      const untailored = computeMetrics('İlköğretim güzel.', undefined, { commonWords });
+      expect(untailored).toMatchObject({ complex_words: 1, complex_word_spans: [[0, 10]] });
+    });
+
+    it('analyzes Hindi and Arabic against their own vocabularies', () => {
+      const options = { autoDetectLanguage: true };
+      const hindi = computeMetrics('मैं स्कूल गया। वह घर गई।', undefined, options);
+      expect(hindi.word_count).toBe(6);
+      expect(hindi.sentence_count).toBe(2);
+      expect(hindi.complex_words).toBe(0);
+      const arabic = computeMetrics('\u200Fذهب إلى المدرسة. هل أنت بخير؟', undefined, options);
+      expect(arabic.word_count).toBe(6);
+      expect(arabic.sentence_count).toBe(2);
+      expect(arabic.complex_words).toBe(0);
+    });
+
+    it('drops, counts or expands numbers as requested', () => {
+      const text = 'I ran 5 km';
+      expect(computeMetrics(text).word_count).toBe(3);
+      const counted = computeMetrics(text, undefined, { numberHandling: 'count' });
+      expect(counted.unique_word_list).toEqual(['5', 'i', 'km', 'ran']);
+      const expanded = computeMetrics(text, undefined, { numberHandling: 'expand' });
+      expect(expanded.unique_word_list).toEqual(['five', 'i', 'km', 'ran']);
+      expect(expanded.numeric_token_count).toBe(1);
+    });
+
+    it('counts numbers too large to expand as words', () => {
+      const metrics = computeMetrics('I ran 5000 m', undefined, { numberHandling: 'expand' });
+      expect(metrics.word_count).toBe(4);
+      expect(metrics.unique_word_list).toContain('5000');
+    });
+
+    it('expands and splits contractions only when the policy splits them', () => {
+      const text = "I don't think it's John's";
+      expect(computeMetrics(text).word_count).toBe(5);
+      const options = { wordCountPolicy: 'split-contractions' as const };
+      const metrics = computeMetrics(text, undefined, options);
+      expect(metrics.word_count).toBe(8);
+      expect(metrics.complex_words).toBe(0);
+    });
+
+    it('judges complex words and their spans on the parts the policy counts', () => {
+      const text = 'An inefficiency-revolution began.';
+      const whole = { complex_words: 1, complex_word_spans: [[3, 26]] };
+      expect(computeMetrics(text)).toMatchObject(whole);
+      const options = { wordCountPolicy: 'split-hyphens' as const };
+      expect(computeMetrics(text, undefined, options)).toMatchObject({
+        complex_words: 2,
+        complex_word_spans: [
+          [3, 15],
+          [16, 26],
+        ],
+      });
+    });
+
+    it('treats hyphenated compounds as single, familiar words', () => {
+      const metrics = computeMetrics('My mother-in-law is well-known');
+      expect(metrics.word_count).toBe(4);
+      expect(metrics.complex_words).toBe(0);
+    });
+
+    it('normalizes smart quotes before tokenizing', () => {
+      const metrics = computeMetrics('“It’s fine,” he said — twice.');
+      expect(metrics.word_count).toBe(5);
+      expect(metrics.complex_words).toBe(0);
+    });
+  });
+});
@@ -0,0 +1,110 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+
+describe('metrics-calculator', () => {
+  describe('input without words', () => {
+    const zeroed = {
+      word_count: 0,
+      unique_words: 0,
+      complex_words: 0,
+      acronym_count: 0,
+      numeric_token_count: 0,
+      cefr_level: 'A1',
+      cefr_confidence: 0,
+      cefr_is_reliable: false,
+      cefr_sentence_score: 0,
+      cefr_vocab_score: 0,
+      cefr_grammar_score: 0,
+      sentence_count: 0,
+      fragment_count: 0,
+      avg_sentence_length: 0,
+      syllable_count: 0,
+      estimated_phoneme_count: 0,
+      flesch_reading_ease: 0,
+      gunning_fog: 0,
+      smog_grade: 0,
+      coleman_liau: 0,
+      dale_chall: 0,
+      automated_readability_index: 0,
+      filler_ratio: 0,
+      type_token_ratio: 0,
+      mtld: 0,
+      lexical_density: 0,
+      average_word_length: 0,
+      fluency_score: 0,
+      complex_word_spans: [],
+      sentence_levels: [],
+      windowed_ttr: [],
+      top_repeated_bigrams: [],
+    };
+
+    for (const text of ['...', '... !!! ???', '   \n\t   ', '👋']) {
+      it(`returns zeroed metrics for ${JSON.stringify(text)}`, () => {
+        const metrics = computeMetrics(text);
+        expect(metrics).toMatchObject(zeroed);
+        expect(Object.values(metrics.band_counts).every((count) => count === 0)).toBe(true);
+      });
+    }
+  });
+
+  describe('serialized shape', () => {
+    it('keeps a stable set of keys', () => {
+      const words = [{ word: 'hello', score: 1 }];
+      const metrics = computeMetrics('Hello world.', words, { durationSecs: 2 });
+      expect(Object.keys(JSON.parse(JSON.stringify(metrics))).sort()).toEqual([
+        'acronym_count',
+        'automated_readability_index',
+        'average_word_length',
+        'avg_sentence_length',
+        'band_counts',
+        'byte_count',
+        'cefr_confidence',
+        'cefr_grammar_score',
+        'cefr_is_reliable',
+        'cefr_level',
+        'cefr_sentence_score',
+        'cefr_vocab_score',
+        'challenging_words',
+        'character_count',
+        'coleman_liau',
+        'complex_word_spans',
+        'complex_words',
+        'contains_flagged_words',
+        'dale_chall',
+        'estimated_phoneme_count',
+        'estimated_reading_time_secs',
+        'estimated_speaking_time_secs',
+        'filler_count',
+        'filler_ratio',
+        'flagged_count',
+        'flesch_reading_ease',
+        'fluency_breakdown',
+        'fluency_score',
+        'fragment_count',
+        'gunning_fog',
+        'lexical_density',
+        'longest_word',
+        'mtld',
+        'numeric_token_count',
+        'pronunciation_score',
+        'repair_count',
+        'repaired_tokens',
+        'sentence_count',
+        'sentence_levels',
+        'smog_grade',
+        'syllable_count',
+        'top_repeated_bigrams',
+        'truncated',
+        'type_token_ratio',
+        'unique_lemmas',
+        'unique_word_list',
+        'unique_words',
+        'windowed_ttr',
+        'word_count',
+        'word_difficulties',
+        'word_frequencies',
+        'words_per_minute',
+      ]);
+    });
+  });
+});
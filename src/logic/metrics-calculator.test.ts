@@ -10,11 +10,24 @@ describe('metrics-calculator', () => {
       expect(metrics.character_count).toBe(27);
     });
 
+    it('counts characters as code points rather than UTF-16 units', () => {
+      const metrics = computeMetrics('naïve café');
+      expect(metrics.character_count).toBe(10);
+      expect(metrics.byte_count).toBe(12);
+    });
+
+    it('counts an emoji as a single character', () => {
+      const metrics = computeMetrics('hi 👋');
+      expect(metrics.character_count).toBe(4);
+      expect(metrics.byte_count).toBe(7);
+    });
+
     it('handles empty input', () => {
       const text = '';
       const metrics = computeMetrics(text);
       expect(metrics.word_count).toBe(0);
       expect(metrics.character_count).toBe(0);
+      expect(metrics.byte_count).toBe(0);
       expect(metrics.cefr_level).toBe('A1');
       expect(metrics.unique_words).toBe(0);
       expect(metrics.complex_words).toBe(0);
@@ -35,20 +48,6 @@ describe('metrics-calculator', () => {
       expect(metrics.unique_words).toBe(6);
     });
 
-    it('calculates low CEFR (A1/A2) for simple text', () => {
-      const text = 'I go to the shop. It is big. I see a cat. The dog is nice.';
-      const metrics = computeMetrics(text);
-      expect(['A1', 'A2']).toContain(metrics.cefr_level);
-    });
-
-    it('calculates high CEFR (B2/C1) for complex text', () => {
-      const text =
-        'A fundamental understanding of intricate algorithms is absolutely essential for comprehensive software development.';
-      const metrics = computeMetrics(text);
-
-      expect(['B2', 'C1', 'C2']).toContain(metrics.cefr_level);
-    });
-
     it('handles noise/punctuation strings', () => {
       const text = '   ...   ';
       const metrics = computeMetrics(text);
@@ -57,30 +56,119 @@ describe('metrics-calculator', () => {
       expect(metrics.cefr_level).toBe('A1');
     });
 
+    it('counts distinct stems separately from surface forms', () => {
+      const metrics = computeMetrics('run ran running runs');
+      expect(metrics.unique_words).toBe(4);
+      expect(metrics.unique_lemmas).toBe(2);
+    });
+
     it('counts unique words correctly (case insensitive)', () => {
       const text = 'Test test TEST';
       const metrics = computeMetrics(text);
       expect(metrics.unique_words).toBe(1);
     });
 
-    it('identifies complex words correctly', () => {
-      const text = 'The xylophone is loud';
-      const metrics = computeMetrics(text);
-      expect(metrics.complex_words).toBe(0);
+    it('lists unique words in alphabetical order', () => {
+      const metrics = computeMetrics('The dog saw the cat. THE end!');
+      expect(metrics.unique_word_list).toEqual(['cat', 'dog', 'end', 'saw', 'the']);
+      expect(metrics.unique_words).toBe(metrics.unique_word_list.length);
     });
 
-    it('requires uncommon AND (long OR academic suffix) for complexity', () => {
-      const text = 'important university information';
-      const metrics = computeMetrics(text);
-      expect(metrics.complex_words).toBe(1);
+    it('reports lexical diversity measures', () => {
+      const metrics = computeMetrics('Test test TEST');
+      expect(metrics.type_token_ratio).toBeCloseTo(1 / 3);
+      expect(metrics.mtld).toBeGreaterThan(0);
     });
 
-    it('incorporates grammar clarity bonus for high quality text', () => {
-      const text = 'The quick brown fox jumps over the lazy dog.';
-      const metrics = computeMetrics(text);
+    it('reports normalized word frequencies', () => {
+      const metrics = computeMetrics('The dog saw the cat. THE end!');
+      expect(metrics.word_frequencies).toEqual({ cat: 1, dog: 1, end: 1, saw: 1, the: 3 });
+    });
+
+    it('reports lexical density from the common-word list', () => {
+      expect(computeMetrics('the cat').lexical_density).toBe(0);
+      expect(computeMetrics('quantum entanglement').lexical_density).toBe(1);
+      expect(computeMetrics('').lexical_density).toBe(0);
+    });
+
+    it('reports vocabulary band counts', () => {
+      const { band_counts } = computeMetrics('The comprehensive xylophone');
+      expect(band_counts.A1).toBe(1);
+      expect(band_counts.C1).toBe(1);
+      expect(band_counts.unknown).toBe(1);
+    });
+
+    it('counts syllables and scores Flesch reading ease', () => {
+      const metrics = computeMetrics('The cat sat on the mat.');
+      expect(metrics.syllable_count).toBe(6);
+      expect(metrics.estimated_phoneme_count).toBe(15);
+      expect(metrics.flesch_reading_ease).toBeGreaterThan(100);
+    });
+
+    it('returns zero reading ease for empty input', () => {
+      const metrics = computeMetrics('');
+      expect(metrics.syllable_count).toBe(0);
+      expect(metrics.flesch_reading_ease).toBe(0);
+      expect(metrics.gunning_fog).toBe(0);
+      expect(metrics.smog_grade).toBe(0);
+      expect(metrics.coleman_liau).toBe(0);
+      expect(metrics.dale_chall).toBe(0);
+      expect(metrics.automated_readability_index).toBe(0);
+    });
+
+    it('scores a higher Gunning Fog index for polysyllabic text', () => {
+      const simple = computeMetrics('The cat sat on the mat.');
+      const complex = computeMetrics('Comprehensive educational opportunities are beneficial.');
+      expect(complex.gunning_fog).toBeGreaterThan(simple.gunning_fog);
+    });
+
+    it('counts filler words and their ratio', () => {
+      const metrics = computeMetrics('Um, I went to, you know, the shop.');
+      expect(metrics.filler_count).toBe(2);
+      expect(metrics.filler_ratio).toBeCloseTo(3 / 8);
+    });
+
+    it('reports a filler ratio of 1 for all-filler speech', () => {
+      const metrics = computeMetrics('Um uh, you know, basically.');
+      expect(metrics.filler_count).toBe(4);
+      expect(metrics.filler_ratio).toBe(1);
+    });
+
+    it('counts immediate word repetitions as repairs', () => {
+      const metrics = computeMetrics('I I I went to the the store.');
+      expect(metrics.repair_count).toBe(2);
+      expect(metrics.repaired_tokens).toBe(3);
+    });
+
+    it('reports sentence count and average sentence length', () => {
+      const metrics = computeMetrics('I like cats. Dogs are nice too!');
+      expect(metrics.sentence_count).toBe(2);
+      expect(metrics.avg_sentence_length).toBe(3.5);
+    });
+
+    it('reports no sentences for empty input', () => {
+      const metrics = computeMetrics('');
+      expect(metrics.sentence_count).toBe(0);
+      expect(metrics.avg_sentence_length).toBe(0);
+    });
+
+    it('counts sentences without a verb as fragments', () => {
+      expect(computeMetrics('The big red dog. The dog ran.').fragment_count).toBe(1);
+      expect(computeMetrics("What a day! I'm tired.").fragment_count).toBe(1);
+    });
+
+    it('counts numeric tokens', () => {
+      expect(computeMetrics('In 2024 I paid $3.50 for 2 apples').numeric_token_count).toBe(3);
+    });
+
+    it('recognises inflected forms of common words', () => {
+      const metrics = computeMetrics('apartments universities questions');
+      expect(metrics.complex_words).toBe(0);
+    });
 
-      expect(metrics.cefr_level).toBeDefined();
-      expect(['A1', 'A2']).toContain(metrics.cefr_level);
+    it('flags repeated bigrams within sentences', () => {
+      const metrics = computeMetrics('I think it is good. I think so. End. Start again.');
+      expect(metrics.top_repeated_bigrams).toEqual([{ bigram: 'i think', count: 2 }]);
     });
   });
 });
@@ -1,147 +1,132 @@
-import { COMMON_WORDS } from './common-words';
-import { GrammarChecker } from './grammar-checker';
+import { characterCount, countNumericTokens } from './text-utils';
+import { countAcronyms } from './proper-nouns';
+import { splitSentences } from './sentence-splitter';
 import {
-  isCEFRClassifierReady,
-  predictCEFR,
-  estimateCEFRHeuristic,
-  type CEFRPrediction,
-} from './cefr-classifier';
-
-export interface Metrics {
-  word_count: number;
-  character_count: number;
-  unique_words: number;
-  complex_words: number;
-  cefr_level: string;
-  pronunciation_score?: number;
-}
-
-const MIN_WORD_LENGTH_FOR_COMPLEXITY = 9;
-const ACADEMIC_SUFFIX_PATTERN = /((tion)|(ment)|(ence)|(ance)|(ity)|(ive)|(ous)|(ism)|(ist))$/;
-const MIN_WORDS_FOR_CEFR = 10;
-const MAX_SENTENCE_LENGTH_SCORE = 12;
-const SENTENCE_SCORE_WEIGHT = 40;
-const VOCAB_SCORE_WEIGHT = 40;
-const MAX_COMPLEX_RATIO = 0.1;
-const GRAMMAR_SCORE_WEIGHT = 20;
-const PRONUNCIATION_SCALE = 100;
-
-const CEFR_THRESHOLDS = {
-  A1: 25,
-  A2: 40,
-  B1: 55,
-  B2: 70,
-  C1: 85,
-} as const;
-
-function calculatePronunciationScore(words?: { word: string; score: number }[]): number {
-  if (!words || words.length === 0) return 0;
-  const totalScore = words.reduce((acc, w) => acc + (w.score || 0), 0);
-  return Math.round((totalScore / words.length) * PRONUNCIATION_SCALE);
-}
-
-function isComplexWord(word: string): boolean {
-  const clean = word.toLowerCase().replace(/[^a-z]/g, '');
-  if (!clean) return false;
-
-  const isCommon = COMMON_WORDS.has(clean);
-  const isLong = clean.length > MIN_WORD_LENGTH_FOR_COMPLEXITY;
-  const hasAcademicSuffix = ACADEMIC_SUFFIX_PATTERN.test(clean);
-
-  return !isCommon && (isLong || hasAcademicSuffix);
-}
-
-function countComplexWords(textWords: string[]): number {
-  return textWords.filter(isComplexWord).length;
-}
-
-function calculateCEFRScore(
-  avgSentenceLen: number,
-  complexRatio: number,
-  clarityScore: number
-): number {
-  const sentScore =
-    (Math.min(avgSentenceLen, MAX_SENTENCE_LENGTH_SCORE) / MAX_SENTENCE_LENGTH_SCORE) *
-    SENTENCE_SCORE_WEIGHT;
-  const vocabScore =
-    (Math.min(complexRatio, MAX_COMPLEX_RATIO) / MAX_COMPLEX_RATIO) * VOCAB_SCORE_WEIGHT;
-  const grammarScore = (clarityScore / 100) * GRAMMAR_SCORE_WEIGHT;
-  return sentScore + vocabScore + grammarScore;
+  analysisWords,
+  capWords,
+  prepareText,
+  resolveCommonWords,
+  resolveOptions,
+} from './metrics-options';
+import { topRepeatedBigrams } from './repetition';
+import { challengingWords } from './challenging-words';
+import { calculateFlaggedMetrics } from './flagged-words';
+import { calculateFluencyMetrics } from './fluency';
+import { windowedTypeTokenRatio } from './lexical-diversity';
+import { wordDifficulties } from './word-difficulty';
+import { estimatePhonemeCount } from './phonemes';
+import { countFragments } from './fragments';
+import { complexWordSpans, scoreSentences, scoreTranscript } from './cefr-transcript';
+import {
+  calculateLexicalMetrics,
+  calculatePronunciationScore,
+  calculateReadabilityMetrics,
+  calculateTimeEstimates,
+  calculateWordLengthMetrics,
+  calculateWordsPerMinute,
+  countBytes,
+} from './metric-groups';
+import type { Metrics, MetricsOptions, WordScores } from './metrics-types';
+
+export type { CefrThresholds } from './cefr-thresholds';
+export type {
+  Language,
+  Metrics,
+  MetricsOptions,
+  NumberHandling,
+  RepeatedBigram,
+  SentenceLevel,
+  SentenceSplitMode,
+  WordCountPolicy,
+} from './metrics-types';
+
+/** The transcript as every metric sees it: resolved options, prepared text and its words. */
+function prepareTranscript(text: string, options: MetricsOptions) {
+  const analysisText = prepareText(text, options);
+  return {
+    text,
+    options,
+    commonWords: resolveCommonWords(options),
+    analysisText,
+    textWords: analysisWords(analysisText, options),
+    sentences: splitSentences(analysisText, options.sentenceSplitMode),
+  };
 }
 
-function determineCEFRLevel(wordCount: number, totalScore: number): string {
-  if (wordCount < MIN_WORDS_FOR_CEFR) return 'A1';
-  if (totalScore < CEFR_THRESHOLDS.A1) return 'A1';
-  if (totalScore < CEFR_THRESHOLDS.A2) return 'A2';
-  if (totalScore < CEFR_THRESHOLDS.B1) return 'B1';
-  if (totalScore < CEFR_THRESHOLDS.B2) return 'B2';
-  if (totalScore < CEFR_THRESHOLDS.C1) return 'C1';
-  return 'C2';
-}
+type PreparedTranscript = ReturnType<typeof prepareTranscript>;
 
-export function computeMetrics(text: string, words?: { word: string; score: number }[]): Metrics {
-  const textWords = text.toLowerCase().match(/\b[a-z']+\b/g) || [];
+function countMetrics({ text, options, textWords, sentences }: PreparedTranscript) {
   const word_count = textWords.length;
-  const character_count = text.length;
-  const unique_words = new Set(textWords).size;
-  const pronunciation_score = calculatePronunciationScore(words);
-
-  const sentences = text.split(/[.!?]+/).filter((s) => s.trim().length > 0);
-  const avgSentenceLen = sentences.length > 0 ? word_count / sentences.length : 0;
-
-  const complexCount = countComplexWords(textWords);
-  const complexRatio = word_count > 0 ? complexCount / word_count : 0;
-
-  const { clarityScore } = GrammarChecker.check(text);
-  const totalScore = calculateCEFRScore(avgSentenceLen, complexRatio, clarityScore);
-  const cefrLevel = determineCEFRLevel(word_count, totalScore);
-
   return {
     word_count,
-    character_count,
-    cefr_level: cefrLevel,
-    unique_words,
-    complex_words: complexCount,
-    pronunciation_score,
+    character_count: characterCount(text),
+    byte_count: countBytes(text),
+    acronym_count: countAcronyms(text),
+    numeric_token_count: countNumericTokens(text),
+    estimated_phoneme_count: estimatePhonemeCount(textWords),
+    sentence_count: sentences.length,
+    fragment_count: countFragments(sentences, options.language),
+    avg_sentence_length: sentences.length > 0 ? word_count / sentences.length : 0,
+    ...calculateTimeEstimates(word_count),
+    ...calculateWordLengthMetrics(textWords),
   };
 }
 
-export interface MetricsWithConfidence extends Metrics {
-  cefr_confidence?: number;
-  cefr_method: 'ml' | 'heuristic';
+function vocabularyMetrics(transcript: PreparedTranscript, word_count: number) {
+  const { text, options, commonWords, textWords } = transcript;
+  const words_per_minute = calculateWordsPerMinute(word_count, options.durationSecs);
+  const lexical = calculateLexicalMetrics(textWords, commonWords, options.language);
+  return {
+    words_per_minute,
+    ...lexical,
+    windowed_ttr: windowedTypeTokenRatio(textWords, options.ttrWindowSize),
+    ...calculateFluencyMetrics({ word_count, words_per_minute, ...lexical }),
+    complex_word_spans: complexWordSpans(text, options),
+    challenging_words: challengingWords(textWords, commonWords),
+    word_difficulties: wordDifficulties(textWords, commonWords),
+    ...calculateFlaggedMetrics(textWords, options.flaggedWords),
+  };
 }
 
-async function getCEFRPrediction(
-  text: string
-): Promise<{ prediction: CEFRPrediction; method: 'ml' | 'heuristic' }> {
-  if (isCEFRClassifierReady()) {
-    try {
-      const prediction = await predictCEFR(text);
-      console.log(
-        `[MetricsCalculator] ML CEFR prediction: ${prediction.level} (${(prediction.confidence * 100).toFixed(1)}%)`
-      );
-      return { prediction, method: 'ml' };
-    } catch (error) {
-      console.warn('[MetricsCalculator] ML prediction failed, using heuristic:', error);
-      return { prediction: estimateCEFRHeuristic(text), method: 'heuristic' };
-    }
-  }
-  const prediction = estimateCEFRHeuristic(text);
-  console.log(`[MetricsCalculator] Using heuristic CEFR: ${prediction.level}`);
-  return { prediction, method: 'heuristic' };
+function scoreMetrics(transcript: PreparedTranscript, words: WordScores | undefined) {
+  const { analysisText, options, textWords, sentences } = transcript;
+  const cefr = scoreTranscript(analysisText, textWords, sentences.length, options);
+  return {
+    cefr_level: cefr.level,
+    cefr_confidence: cefr.confidence,
+    cefr_is_reliable: cefr.reliable,
+    cefr_sentence_score: cefr.components.sentence,
+    cefr_vocab_score: cefr.components.vocabulary,
+    cefr_grammar_score: cefr.components.grammar,
+    complex_words: cefr.complexCount,
+    pronunciation_score: calculatePronunciationScore(words),
+    ...calculateReadabilityMetrics(textWords, sentences.length, cefr.complexCount),
+    sentence_levels: scoreSentences(sentences, options),
+    top_repeated_bigrams: topRepeatedBigrams(sentences),
+  };
 }
 
-export async function computeMetricsWithML(
+function analyzeTranscript(
   text: string,
-  words?: { word: string; score: number }[]
-): Promise<MetricsWithConfidence> {
-  const baseMetrics = computeMetrics(text, words);
-  const { prediction, method } = await getCEFRPrediction(text);
-
+  words: WordScores | undefined,
+  options: MetricsOptions
+): Omit<Metrics, 'truncated'> {
+  const transcript = prepareTranscript(text, options);
+  const counts = countMetrics(transcript);
   return {
-    ...baseMetrics,
-    cefr_level: prediction.level,
-    cefr_confidence: prediction.confidence,
-    cefr_method: method,
+    ...counts,
+    ...vocabularyMetrics(transcript, counts.word_count),
+    ...scoreMetrics(transcript, words),
   };
 }
+
+/** Metrics for the transcript, or for its first `maxWords` words when a cap is set. */
+export function computeMetrics(
+  text: string,
+  words?: WordScores,
+  metricsOptions: MetricsOptions = {}
+): Metrics {
+  const options = resolveOptions(text, metricsOptions);
+  const capped = capWords(text, options);
+  return { ...analyzeTranscript(capped.text, words, options), truncated: capped.truncated };
+}
@@ -0,0 +1,32 @@
+import { describe, it, expect } from 'vitest';
+import { CSV_COLUMNS, metricsToCsv } from './metrics-csv';
+import { computeMetrics } from './metrics-calculator';
+
+describe('metrics-csv', () => {
+  const metrics = computeMetrics('I like cats.');
+
+  it('writes a header row followed by one row per entry', () => {
+    const lines = metricsToCsv([
+      ['alice', metrics],
+      ['bob', metrics],
+    ]).split('\n');
+    expect(lines).toHaveLength(3);
+    expect(lines[0]).toBe(['label', ...CSV_COLUMNS].join(','));
+    expect(lines[1].startsWith('alice,3,')).toBe(true);
+  });
+
+  it('quotes labels containing commas or quotes', () => {
+    const [, row] = metricsToCsv([['Smith, "Jo"', metrics]]).split('\n');
+    expect(row.startsWith('"Smith, ""Jo""",3,')).toBe(true);
+  });
+
+  it('leaves missing optional values empty', () => {
+    const [, row] = metricsToCsv([['a', metrics]]).split('\n');
+    const wpmIndex = CSV_COLUMNS.indexOf('words_per_minute') + 1;
+    expect(row.split(',')[wpmIndex]).toBe('');
+  });
+
+  it('writes only the header for no rows', () => {
+    expect(metricsToCsv([])).toBe(['label', ...CSV_COLUMNS].join(','));
+  });
+});
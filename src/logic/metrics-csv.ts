@@ -0,0 +1,46 @@
+import type { Metrics } from './metrics-types';
+
+/** Columns after the leading `label` column, in output order. Only scalar fields are exported. */
+export const CSV_COLUMNS: readonly (keyof Metrics)[] = [
+  'word_count',
+  'character_count',
+  'unique_words',
+  'complex_words',
+  'cefr_level',
+  'cefr_confidence',
+  'pronunciation_score',
+  'words_per_minute',
+  'flesch_reading_ease',
+  'gunning_fog',
+  'smog_grade',
+  'coleman_liau',
+  'dale_chall',
+  'automated_readability_index',
+  'filler_count',
+  'filler_ratio',
+  'type_token_ratio',
+  'mtld',
+  'lexical_density',
+  'average_word_length',
+  'sentence_count',
+  'avg_sentence_length',
+];
+
+const NEEDS_QUOTING_PATTERN = /[",\r\n]/;
+
+function csvField(value: unknown): string {
+  const text = value === undefined ? '' : String(value);
+  return NEEDS_QUOTING_PATTERN.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+/**
+ * Serializes labelled metrics as CSV: a header row, then one row per entry with the label
+ * first and CSV_COLUMNS after it. Fields containing commas, quotes or newlines are quoted.
+ */
+export function metricsToCsv(rows: [label: string, metrics: Metrics][]): string {
+  const header = ['label', ...CSV_COLUMNS].join(',');
+  const lines = rows.map(([label, metrics]) =>
+    [label, ...CSV_COLUMNS.map((column) => metrics[column])].map(csvField).join(',')
+  );
+  return [header, ...lines].join('\n');
+}
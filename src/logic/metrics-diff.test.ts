@@ -0,0 +1,29 @@
+import { describe, it, expect } from 'vitest';
+import { diffMetrics } from './metrics-diff';
+
+describe('metrics-diff', () => {
+  const previous = { word_count: 80, unique_words: 50, complex_words: 4, cefr_level: 'B1' };
+
+  it('reports signed changes including a band gain', () => {
+    const current = { word_count: 120, unique_words: 45, complex_words: 9, cefr_level: 'B2' };
+    expect(diffMetrics(current, previous)).toEqual({
+      word_count: 40,
+      unique_words: -5,
+      complex_words: 5,
+      cefr_band: 1,
+    });
+  });
+
+  it('reports a band drop as negative', () => {
+    expect(diffMetrics({ ...previous, cefr_level: 'A2' }, previous).cefr_band).toBe(-1);
+  });
+
+  it('reports no change against itself', () => {
+    expect(diffMetrics(previous, previous)).toEqual({
+      word_count: 0,
+      unique_words: 0,
+      complex_words: 0,
+      cefr_band: 0,
+    });
+  });
+});
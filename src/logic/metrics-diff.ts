@@ -0,0 +1,25 @@
+import { cefrLevelIndex } from './level-gap';
+import type { Metrics } from './metrics-types';
+
+export interface MetricsDelta {
+  word_count: number;
+  unique_words: number;
+  complex_words: number;
+  /** CEFR bands gained since the previous result, e.g. +1 for B1 to B2. */
+  cefr_band: number;
+}
+
+type DiffableMetrics = Pick<
+  Metrics,
+  'word_count' | 'unique_words' | 'complex_words' | 'cefr_level'
+>;
+
+/** Signed changes from a previous result to the current one, for progress tracking. */
+export function diffMetrics(current: DiffableMetrics, previous: DiffableMetrics): MetricsDelta {
+  return {
+    word_count: current.word_count - previous.word_count,
+    unique_words: current.unique_words - previous.unique_words,
+    complex_words: current.complex_words - previous.complex_words,
+    cefr_band: cefrLevelIndex(current.cefr_level) - cefrLevelIndex(previous.cefr_level),
+  };
+}
@@ -0,0 +1,74 @@
+import { describe, it, expect } from 'vitest';
+import { mergeMetrics } from './metrics-merge';
+import { levelForScore } from './cefr-scoring';
+import { computeMetrics } from './metrics-calculator';
+import { computeMetricsWithDuration } from './metrics-variants';
+
+describe('mergeMetrics', () => {
+  const first = 'Um I went to the the market. We bought fresh bread.';
+  const second = 'Then we walked home through the beautiful neighbourhood.';
+
+  it('matches analyzing the concatenation for additive fields', () => {
+    const merged = mergeMetrics([computeMetrics(first), computeMetrics(second)]);
+    const whole = computeMetrics(`${first} ${second}`);
+    for (const field of [
+      'word_count',
+      'complex_words',
+      'syllable_count',
+      'estimated_phoneme_count',
+      'filler_count',
+      'repair_count',
+      'sentence_count',
+      'fragment_count',
+      'unique_words',
+      'unique_word_list',
+      'word_frequencies',
+      'band_counts',
+      'type_token_ratio',
+      'avg_sentence_length',
+      'sentence_levels',
+      'top_repeated_bigrams',
+    ] as const) {
+      expect(merged[field]).toEqual(whole[field]);
+    }
+    expect(merged.filler_ratio).toBeCloseTo(whole.filler_ratio);
+  });
+
+  it('recomputes words per minute from the implied speaking time', () => {
+    const merged = mergeMetrics([
+      computeMetricsWithDuration('one two three four five six', 3),
+      computeMetricsWithDuration('one two three', 6),
+    ]);
+    expect(merged.words_per_minute).toBeCloseTo(60);
+  });
+
+  it('scores the CEFR level from the components averaged by word count', () => {
+    const short = {
+      ...computeMetrics('Hi.'),
+      cefr_sentence_score: 40,
+      cefr_vocab_score: 40,
+      cefr_grammar_score: 20,
+    };
+    const long = { ...computeMetrics(first), cefr_level: 'C2' };
+    const longScore = long.cefr_sentence_score + long.cefr_vocab_score + long.cefr_grammar_score;
+    const words = short.word_count + long.word_count;
+    const score = (100 * short.word_count + longScore * long.word_count) / words;
+    expect(mergeMetrics([short, long]).cefr_level).toBe(levelForScore(score));
+    expect(mergeMetrics([long]).cefr_level).toBe(computeMetrics(first).cefr_level);
+  });
+
+  it('ranks the merged challenging words by syllable count', () => {
+    const merged = mergeMetrics([
+      computeMetrics('The magnificent view was wonderful.'),
+      computeMetrics('An extraordinary opportunity appeared.'),
+    ]);
+    const whole = computeMetrics(
+      'The magnificent view was wonderful. An extraordinary opportunity appeared.'
+    );
+    expect(merged.challenging_words).toEqual(whole.challenging_words);
+  });
+
+  it('returns empty totals for no parts', () => {
+    expect(mergeMetrics([])).toMatchObject({ word_count: 0, cefr_level: 'A1', unique_words: 0 });
+  });
+});
@@ -0,0 +1,150 @@
+import { MAX_CHALLENGING_WORDS, compareChallenging } from './challenging-words';
+import { calculateFluencyMetrics } from './fluency';
+import { topRepeatedBigrams } from './repetition';
+import { uniqueLemmaCount } from './lexical-diversity';
+import { levelForScore } from './cefr-scoring';
+import type { Metrics } from './metrics-types';
+
+const SUMMED_FIELDS = [
+  'word_count',
+  'character_count',
+  'byte_count',
+  'complex_words',
+  'acronym_count',
+  'numeric_token_count',
+  'syllable_count',
+  'estimated_phoneme_count',
+  'filler_count',
+  'repair_count',
+  'repaired_tokens',
+  'sentence_count',
+  'fragment_count',
+  'flagged_count',
+  'estimated_reading_time_secs',
+  'estimated_speaking_time_secs',
+] as const;
+
+const AVERAGED_FIELDS = [
+  'cefr_confidence',
+  'cefr_sentence_score',
+  'cefr_vocab_score',
+  'cefr_grammar_score',
+  'filler_ratio',
+  'flesch_reading_ease',
+  'gunning_fog',
+  'smog_grade',
+  'coleman_liau',
+  'dale_chall',
+  'automated_readability_index',
+  'mtld',
+  'lexical_density',
+  'average_word_length',
+] as const;
+
+function sum(values: number[]): number {
+  return values.reduce((total, value) => total + value, 0);
+}
+
+/** Mean of the defined values, weighted by each part's word count. */
+function weightedMean(parts: Metrics[], value: (part: Metrics) => number | undefined) {
+  const weighted = parts.filter((part) => value(part) !== undefined && part.word_count > 0);
+  const words = sum(weighted.map((part) => part.word_count));
+  if (words === 0) return undefined;
+  return sum(weighted.map((part) => (value(part) ?? 0) * part.word_count)) / words;
+}
+
+function sumCounts(records: Record<string, number>[]): Record<string, number> {
+  const totals = new Map<string, number>();
+  for (const record of records) {
+    for (const [key, count] of Object.entries(record)) {
+      totals.set(key, (totals.get(key) ?? 0) + count);
+    }
+  }
+  const sorted = [...totals].sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0));
+  return Object.fromEntries(sorted);
+}
+
+/** Overall rate from the speaking time implied by each part; needs every part timed. */
+function mergedWordsPerMinute(parts: Metrics[]): number | undefined {
+  if (parts.some((part) => part.words_per_minute === undefined)) return undefined;
+  const minutes = sum(parts.map((part) => part.word_count / (part.words_per_minute || Infinity)));
+  return minutes > 0 ? sum(parts.map((part) => part.word_count)) / minutes : undefined;
+}
+
+/** Heuristic level for the averaged components, scored with the default boundaries. */
+function mergedLevel(averages: ReturnType<typeof averageFields>): string {
+  const { cefr_sentence_score, cefr_vocab_score, cefr_grammar_score } = averages;
+  return levelForScore(cefr_sentence_score + cefr_vocab_score + cefr_grammar_score);
+}
+
+function mergedLongestWord(parts: Metrics[]): string {
+  let longest = '';
+  for (const { longest_word } of parts) {
+    if ([...longest_word].length > [...longest].length) longest = longest_word;
+  }
+  return longest;
+}
+
+function sumFields(parts: Metrics[]) {
+  const entries = SUMMED_FIELDS.map((field) => [field, sum(parts.map((part) => part[field]))]);
+  return Object.fromEntries(entries) as Record<(typeof SUMMED_FIELDS)[number], number>;
+}
+
+function averageFields(parts: Metrics[]) {
+  const entries = AVERAGED_FIELDS.map((field) => [
+    field,
+    weightedMean(parts, (part) => part[field]) ?? 0,
+  ]);
+  return Object.fromEntries(entries) as Record<(typeof AVERAGED_FIELDS)[number], number>;
+}
+
+/**
+ * Combines the metrics of several utterances into one session summary.
+ * - Summed: SUMMED_FIELDS, word_frequencies and band_counts.
+ * - Averaged, weighted by word count: AVERAGED_FIELDS and pronunciation_score.
+ * - Recomputed from the totals: unique words and lemmas, type-token ratio, average sentence length,
+ *   words per minute, repeated bigrams, flagged-word presence and the fluency score.
+ * - cefr_level is scored from the averaged CEFR components, so a model level is not kept.
+ * - Unioned: unique_word_list, word_difficulties and challenging_words (re-ranked and capped).
+ * - Concatenated: sentence_levels and windowed_ttr.
+ * - truncated is set when any part was truncated.
+ * complex_word_spans and rate_variability refer to individual transcripts and are dropped.
+ */
+export function mergeMetrics(parts: Metrics[]): Metrics {
+  const totals = sumFields(parts);
+  const { word_count, sentence_count } = totals;
+  const unique_word_list = [...new Set(parts.flatMap((part) => part.unique_word_list))].sort();
+  const sentence_levels = parts.flatMap((part) => part.sentence_levels);
+  const averages = averageFields(parts);
+  const words_per_minute = mergedWordsPerMinute(parts);
+  const challenging_words = [...new Set(parts.flatMap((part) => part.challenging_words))]
+    .sort(compareChallenging)
+    .slice(0, MAX_CHALLENGING_WORDS);
+  const word_difficulties = new Map(parts.flatMap((part) => part.word_difficulties));
+
+  return {
+    ...totals,
+    ...averages,
+    unique_words: unique_word_list.length,
+    unique_word_list,
+    unique_lemmas: uniqueLemmaCount(unique_word_list),
+    complex_word_spans: [],
+    challenging_words,
+    word_difficulties: [...word_difficulties],
+    cefr_level: mergedLevel(averages),
+    cefr_is_reliable: parts.some((part) => part.cefr_is_reliable),
+    pronunciation_score: weightedMean(parts, (part) => part.pronunciation_score),
+    words_per_minute,
+    type_token_ratio: word_count > 0 ? unique_word_list.length / word_count : 0,
+    ...calculateFluencyMetrics({ ...totals, ...averages, words_per_minute }),
+    word_frequencies: sumCounts(parts.map((part) => part.word_frequencies)),
+    band_counts: sumCounts(parts.map((part) => part.band_counts)),
+    longest_word: mergedLongestWord(parts),
+    avg_sentence_length: sentence_count > 0 ? word_count / sentence_count : 0,
+    windowed_ttr: parts.flatMap((part) => part.windowed_ttr),
+    sentence_levels,
+    top_repeated_bigrams: topRepeatedBigrams(sentence_levels.map((sentence) => sentence.text)),
+    contains_flagged_words: totals.flagged_count > 0,
+    truncated: parts.some((part) => part.truncated),
+  };
+}
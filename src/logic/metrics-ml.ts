@@ -0,0 +1,50 @@
+import { computeMetrics } from './metrics-calculator';
+import { capWords, resolveOptions } from './metrics-options';
+import {
+  isCEFRClassifierReady,
+  predictCEFR,
+  estimateCEFRHeuristic,
+  type CEFRPrediction,
+} from './cefr-classifier';
+import type { Metrics, MetricsOptions, WordScores } from './metrics-types';
+
+export interface MetricsWithConfidence extends Metrics {
+  cefr_method: 'ml' | 'heuristic';
+}
+
+async function getCEFRPrediction(
+  text: string
+): Promise<{ prediction: CEFRPrediction; method: 'ml' | 'heuristic' }> {
+  if (isCEFRClassifierReady()) {
+    try {
+      const prediction = await predictCEFR(text);
+      console.log(
+        `[MetricsCalculator] ML CEFR prediction: ${prediction.level} (${(prediction.confidence * 100).toFixed(1)}%)`
+      );
+      return { prediction, method: 'ml' };
+    } catch (error) {
+      console.warn('[MetricsCalculator] ML prediction failed, using heuristic:', error);
+      return { prediction: estimateCEFRHeuristic(text), method: 'heuristic' };
+    }
+  }
+  const prediction = estimateCEFRHeuristic(text);
+  console.log(`[MetricsCalculator] Using heuristic CEFR: ${prediction.level}`);
+  return { prediction, method: 'heuristic' };
+}
+
+export async function computeMetricsWithML(
+  text: string,
+  words?: WordScores,
+  options: MetricsOptions = {}
+): Promise<MetricsWithConfidence> {
+  const baseMetrics = computeMetrics(text, words, options);
+  const analyzedText = capWords(text, resolveOptions(text, options)).text;
+  const { prediction, method } = await getCEFRPrediction(analyzedText);
+
+  return {
+    ...baseMetrics,
+    cefr_level: prediction.level,
+    cefr_confidence: prediction.confidence,
+    cefr_method: method,
+  };
+}
@@ -0,0 +1,98 @@
+import { expandContractions } from './contractions';
+import { detectLanguage } from './language-detector';
+import { validateThresholds } from './cefr-thresholds';
+import { normalizeTranscript } from './transcript-normalizer';
+import { expandNumbers } from './number-words';
+import { extractWords, truncateWords, type Token } from './text-utils';
+import { vocabularyWords } from './proper-nouns';
+import { DEFAULT_LANGUAGE, getCommonWords } from './vocabulary';
+import type { MetricsOptions, WordCountPolicy } from './metrics-types';
+
+/**
+ * Fills in a detected language when auto-detection is requested and none was given. Throws
+ * if `thresholds` fail validation, so every entry point rejects them before scoring.
+ */
+export function resolveOptions(text: string, options: MetricsOptions): MetricsOptions {
+  if (options.thresholds) validateThresholds(options.thresholds);
+  if (options.language || !options.autoDetectLanguage) return options;
+  return { ...options, language: detectLanguage(text) };
+}
+
+/**
+ * Whether capitalized mid-sentence words count towards vocabulary. German capitalizes every
+ * noun, so the proper-noun heuristic would drop most of its vocabulary; it defaults to on there.
+ */
+export function countsProperNouns(options: MetricsOptions): boolean {
+  return options.countProperNouns ?? options.language === 'de';
+}
+
+/** vocabularyWords of prepared text under the options' proper-noun rule, locale and policy. */
+export function analysisVocabulary(
+  preparedText: string,
+  options: MetricsOptions,
+  startsSentence = true
+): string[] {
+  const proper = countsProperNouns(options);
+  const words = vocabularyWords(preparedText, proper, startsSentence, options.locale);
+  return splitByPolicy(words, options.wordCountPolicy);
+}
+
+export function resolveCommonWords(options: MetricsOptions): ReadonlySet<string> {
+  return options.commonWords ?? getCommonWords(options.language);
+}
+
+/**
+ * Cleans up formatting noise, then applies language-specific normalization. Numbers are
+ * spelled out in English, so `numberHandling: 'expand'` suits English transcripts. English
+ * contractions are expanded only under the 'split-contractions' policy.
+ */
+export function prepareText(text: string, options: MetricsOptions): string {
+  let prepared = normalizeTranscript(text);
+  if (options.numberHandling === 'expand') prepared = expandNumbers(prepared);
+  const language = options.language ?? DEFAULT_LANGUAGE;
+  const splitsContractions = options.wordCountPolicy === 'split-contractions';
+  return language === 'en' && splitsContractions ? expandContractions(prepared) : prepared;
+}
+
+function policySeparator(policy: WordCountPolicy = 'single-token'): string | undefined {
+  if (policy === 'split-hyphens') return '-';
+  if (policy === 'split-contractions') return "'";
+  return undefined;
+}
+
+function splitByPolicy(words: string[], policy?: WordCountPolicy): string[] {
+  const separator = policySeparator(policy);
+  return separator ? words.flatMap((word) => word.split(separator)) : words;
+}
+
+/** Splits tokens into the parts `policy` counts, each with its own offsets. */
+export function splitTokensByPolicy(tokens: Token[], policy?: WordCountPolicy): Token[] {
+  const separator = policySeparator(policy);
+  if (!separator) return tokens;
+  return tokens.flatMap((token) => {
+    let start = token.start;
+    return token.text.split(separator).map((text) => {
+      const part = { text, start, end: start + text.length };
+      start = part.end + separator.length;
+      return part;
+    });
+  });
+}
+
+/**
+ * Words of prepared text, counting numbers as words unless `numberHandling` drops them,
+ * lowercasing with the rules of `locale` and split as `wordCountPolicy` counts them.
+ */
+export function analysisWords(preparedText: string, options: MetricsOptions): string[] {
+  const includeNumbers = (options.numberHandling ?? 'drop') !== 'drop';
+  const words = extractWords(preparedText, { includeNumbers, locale: options.locale });
+  return splitByPolicy(words, options.wordCountPolicy);
+}
+
+/** Cuts text to its first `maxWords` words, counted as analysisWords counts them. */
+export function capWords(text: string, options: MetricsOptions) {
+  return truncateWords(text, options.maxWords, {
+    includeNumbers: (options.numberHandling ?? 'drop') !== 'drop',
+    wordsIn: (token) => analysisWords(prepareText(token, options), options).length,
+  });
+}
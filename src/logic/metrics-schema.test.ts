@@ -0,0 +1,23 @@
+import { describe, it, expect } from 'vitest';
+import { metricsJsonSchema } from './metrics-schema';
+import { computeMetricsTimed } from './timed-metrics';
+
+describe('metrics-schema', () => {
+  const schema = JSON.parse(metricsJsonSchema());
+
+  it('is valid JSON describing the core fields', () => {
+    expect(schema.title).toBe('Metrics');
+    expect(schema.properties.word_count.type).toBe('integer');
+    expect(schema.properties.cefr_level.type).toBe('string');
+    expect(schema.required).toContain('word_count');
+    expect(schema.required).not.toContain('words_per_minute');
+  });
+
+  it('lists exactly the fields computeMetrics returns', () => {
+    const metrics = computeMetricsTimed([
+      ['Hello world.', 2],
+      ['Goodbye.', 1],
+    ]);
+    expect(Object.keys(schema.properties).sort()).toEqual(Object.keys(metrics).sort());
+  });
+});
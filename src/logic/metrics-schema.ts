@@ -0,0 +1,107 @@
+const INTEGER = { type: 'integer', minimum: 0 };
+const NUMBER = { type: 'number' };
+const STRING = { type: 'string' };
+const BOOLEAN = { type: 'boolean' };
+
+const INTEGER_FIELDS = [
+  'word_count',
+  'character_count',
+  'byte_count',
+  'unique_words',
+  'unique_lemmas',
+  'complex_words',
+  'acronym_count',
+  'numeric_token_count',
+  'syllable_count',
+  'estimated_phoneme_count',
+  'filler_count',
+  'repair_count',
+  'repaired_tokens',
+  'sentence_count',
+  'fragment_count',
+  'flagged_count',
+];
+
+const NUMBER_FIELDS = [
+  'cefr_confidence',
+  'cefr_sentence_score',
+  'cefr_vocab_score',
+  'cefr_grammar_score',
+  'pronunciation_score',
+  'words_per_minute',
+  'rate_variability',
+  'estimated_reading_time_secs',
+  'estimated_speaking_time_secs',
+  'flesch_reading_ease',
+  'gunning_fog',
+  'smog_grade',
+  'coleman_liau',
+  'dale_chall',
+  'automated_readability_index',
+  'filler_ratio',
+  'fluency_score',
+  'type_token_ratio',
+  'mtld',
+  'lexical_density',
+  'average_word_length',
+  'avg_sentence_length',
+];
+
+const OPTIONAL_FIELDS = new Set(['pronunciation_score', 'words_per_minute', 'rate_variability']);
+
+function objectOf(properties: Record<string, object>) {
+  return { type: 'object', properties, required: Object.keys(properties) };
+}
+
+const STRUCTURED_FIELDS: Record<string, object> = {
+  cefr_level: STRING,
+  longest_word: STRING,
+  cefr_is_reliable: BOOLEAN,
+  contains_flagged_words: BOOLEAN,
+  truncated: BOOLEAN,
+  complex_word_spans: {
+    type: 'array',
+    items: { type: 'array', items: INTEGER, minItems: 2, maxItems: 2 },
+  },
+  challenging_words: { type: 'array', items: STRING },
+  word_difficulties: {
+    type: 'array',
+    items: { type: 'array', prefixItems: [STRING, NUMBER], items: false, minItems: 2 },
+  },
+  unique_word_list: { type: 'array', items: STRING },
+  windowed_ttr: { type: 'array', items: NUMBER },
+  word_frequencies: { type: 'object', additionalProperties: INTEGER },
+  band_counts: { type: 'object', additionalProperties: INTEGER },
+  sentence_levels: {
+    type: 'array',
+    items: objectOf({ text: STRING, cefr_level: STRING, word_count: INTEGER }),
+  },
+  top_repeated_bigrams: { type: 'array', items: objectOf({ bigram: STRING, count: INTEGER }) },
+  fluency_breakdown: {
+    type: 'object',
+    properties: { fillers: NUMBER, pace: NUMBER, repairs: NUMBER, diversity: NUMBER },
+    additionalProperties: false,
+  },
+};
+
+function metricsProperties(): Record<string, object> {
+  return {
+    ...Object.fromEntries(INTEGER_FIELDS.map((field) => [field, INTEGER])),
+    ...Object.fromEntries(NUMBER_FIELDS.map((field) => [field, NUMBER])),
+    ...STRUCTURED_FIELDS,
+  };
+}
+
+/** JSON Schema (draft 2020-12) describing the Metrics object, for generating typed clients. */
+export function metricsJsonSchema(): string {
+  const properties = metricsProperties();
+  const schema = {
+    $schema: 'https://json-schema.org/draft/2020-12/schema',
+    title: 'Metrics',
+    type: 'object',
+    properties,
+    required: Object.keys(properties).filter((field) => !OPTIONAL_FIELDS.has(field)),
+    additionalProperties: false,
+  };
+  return JSON.stringify(schema, null, 2);
+}
@@ -0,0 +1,122 @@
+import type { SentenceLevel } from './cefr-scoring';
+import type { Language } from './vocabulary';
+import type { RepeatedBigram } from './repetition';
+import type { CefrThresholds } from './cefr-thresholds';
+import type { SentenceSplitMode } from './sentence-splitter';
+import type { FluencyBreakdown } from './fluency';
+import type { NumberHandling } from './number-words';
+import type { WordCountPolicy } from './word-count';
+
+export type { SentenceLevel } from './cefr-scoring';
+export type { Language } from './vocabulary';
+export type { RepeatedBigram } from './repetition';
+export type { SentenceSplitMode } from './sentence-splitter';
+export type { FluencyBreakdown } from './fluency';
+export type { NumberHandling } from './number-words';
+export type { WordCountPolicy } from './word-count';
+
+/**
+ * The JSON shape returned by computeMetrics. Keys are stable and covered by a test, so
+ * renaming or removing one is a breaking change for consumers.
+ */
+export interface Metrics {
+  word_count: number;
+  character_count: number;
+  byte_count: number;
+  unique_words: number;
+  /** Distinct normalized words in alphabetical (code unit) order. */
+  unique_word_list: string[];
+  /** Distinct stems; irregular forms such as "ran" are not folded into "run". */
+  unique_lemmas: number;
+  complex_words: number;
+  /** All-caps words such as "NASA", which are left out of complex_words. */
+  acronym_count: number;
+  /** [start, end) UTF-16 offsets of each complex word in the original transcript. */
+  complex_word_spans: [number, number][];
+  numeric_token_count: number;
+  challenging_words: string[];
+  /** 0-1 difficulty of each distinct word, in order of first appearance. */
+  word_difficulties: [string, number][];
+  cefr_level: string;
+  cefr_confidence: number;
+  /** False when the transcript is shorter than `thresholds.minWords`. */
+  cefr_is_reliable: boolean;
+  /** Heuristic CEFR points from sentence length, vocabulary and grammar; see explainCefr. */
+  cefr_sentence_score: number;
+  cefr_vocab_score: number;
+  cefr_grammar_score: number;
+  pronunciation_score?: number;
+  words_per_minute?: number;
+  estimated_reading_time_secs: number;
+  estimated_speaking_time_secs: number;
+  /** Spread of per-sentence words per minute; only set by computeMetricsTimed. */
+  rate_variability?: number;
+  syllable_count: number;
+  /** Rough phoneme total from spelling, as a measure of articulatory load. */
+  estimated_phoneme_count: number;
+  flesch_reading_ease: number;
+  gunning_fog: number;
+  smog_grade: number;
+  coleman_liau: number;
+  dale_chall: number;
+  automated_readability_index: number;
+  filler_count: number;
+  filler_ratio: number;
+  repair_count: number;
+  repaired_tokens: number;
+  /** 0-100 composite of fillers, pace, repairs and diversity; see FLUENCY_WEIGHTS. */
+  fluency_score: number;
+  fluency_breakdown: FluencyBreakdown;
+  type_token_ratio: number;
+  mtld: number;
+  /** Type-token ratio per window of `ttrWindowSize` words, in transcript order. */
+  windowed_ttr: number[];
+  word_frequencies: Record<string, number>;
+  lexical_density: number;
+  /** Word counts per CEFR vocabulary band (A1..C2) plus "unknown". */
+  band_counts: Record<string, number>;
+  longest_word: string;
+  average_word_length: number;
+  sentence_count: number;
+  /** Sentences with no recognized verb, a rough count of fragments; English only. */
+  fragment_count: number;
+  avg_sentence_length: number;
+  sentence_levels: SentenceLevel[];
+  top_repeated_bigrams: RepeatedBigram[];
+  contains_flagged_words: boolean;
+  flagged_count: number;
+  /** Whether the transcript was cut to `maxWords` before analysis. */
+  truncated: boolean;
+}
+
+export interface MetricsOptions {
+  durationSecs?: number;
+  language?: Language;
+  /** Guess the language from the transcript when `language` is not given. */
+  autoDetectLanguage?: boolean;
+  commonWords?: ReadonlySet<string>;
+  /** Custom CEFR caps, weights and boundaries; invalid ones throw (see validateThresholds). */
+  thresholds?: CefrThresholds;
+  /** Report A1 for transcripts below `thresholds.minWords`, as before `cefr_is_reliable`. */
+  forceA1WhenShort?: boolean;
+  sentenceSplitMode?: SentenceSplitMode;
+  /** Count capitalized mid-sentence words (likely names) towards complex words; on for German. */
+  countProperNouns?: boolean;
+  /**
+   * Digits are dropped by default. 'count' treats each number as a word; 'expand' spells
+   * out whole numbers up to 999 ("5" becomes "five") and counts larger ones as words.
+   */
+  numberHandling?: NumberHandling;
+  /** How hyphenated and apostrophe-joined forms count as words; 'single-token' by default. */
+  wordCountPolicy?: WordCountPolicy;
+  /** Locale for lowercasing words, such as 'tr' for Turkish dotted and dotless i. */
+  locale?: string;
+  /** Words per windowed_ttr window; defaults to 50. */
+  ttrWindowSize?: number;
+  /** Normalized words to flag instead of the built-in FLAGGED_WORDS. */
+  flaggedWords?: ReadonlySet<string>;
+  /** Analyze only the first `maxWords` words; unlimited by default. */
+  maxWords?: number;
+}
+
+export type WordScores = { word: string; score: number }[];
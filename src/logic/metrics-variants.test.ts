@@ -0,0 +1,120 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+import {
+  computeCEFRLevel,
+  computeMetricsFromBytes,
+  computeMetricsWithDuration,
+  computeMetricsWithFlagged,
+  computeMetricsWithThresholds,
+  computeMetricsWithVocab,
+} from './metrics-variants';
+import { DEFAULT_CEFR_THRESHOLDS } from './cefr-thresholds';
+
+describe('metrics-variants', () => {
+  describe('computeMetricsFromBytes', () => {
+    it('matches computeMetrics on the decoded text', () => {
+      const text = 'Naïve café owners smile.';
+      const bytes = new TextEncoder().encode(text);
+      expect(computeMetricsFromBytes(bytes)).toEqual(computeMetrics(text));
+    });
+
+    it('rejects invalid UTF-8', () => {
+      expect(() => computeMetricsFromBytes(new Uint8Array([0x68, 0xff, 0x69]))).toThrow(TypeError);
+    });
+  });
+
+  describe('computeMetricsWithDuration', () => {
+    it('calculates words per minute from the duration', () => {
+      const metrics = computeMetricsWithDuration('one two three four five six', 3);
+      expect(metrics.words_per_minute).toBe(120);
+    });
+
+    it('leaves words per minute undefined for zero or negative durations', () => {
+      expect(computeMetricsWithDuration('hello world', 0).words_per_minute).toBeUndefined();
+      expect(computeMetricsWithDuration('hello world', -5).words_per_minute).toBeUndefined();
+    });
+
+    it('leaves words per minute undefined when no duration is given', () => {
+      expect(computeMetrics('hello world').words_per_minute).toBeUndefined();
+    });
+  });
+
+  describe('computeMetricsWithVocab', () => {
+    it('treats words in the custom list as familiar', () => {
+      const text = 'We deploy with kubernetes orchestration';
+      expect(computeMetrics(text).complex_words).toBe(2);
+      expect(computeMetricsWithVocab(text, ['kubernetes', 'Orchestration']).complex_words).toBe(0);
+    });
+
+    it('replaces rather than extends the built-in list', () => {
+      expect(computeMetrics('university').complex_words).toBe(0);
+      expect(computeMetricsWithVocab('university', []).complex_words).toBe(1);
+    });
+  });
+
+  describe('computeMetricsWithFlagged', () => {
+    it('flags the built-in list by default without substring matches', () => {
+      expect(computeMetrics('My assistant said damn.')).toMatchObject({
+        contains_flagged_words: true,
+        flagged_count: 1,
+      });
+      expect(computeMetrics('My assistant helped.').contains_flagged_words).toBe(false);
+    });
+
+    it('matches a custom list case-insensitively', () => {
+      const metrics = computeMetricsWithFlagged('Silly SILLY goose', ['silly']);
+      expect(metrics.flagged_count).toBe(2);
+    });
+  });
+
+  describe('computeMetricsWithThresholds', () => {
+    const text =
+      'I think that learning a new language is very interesting but also quite difficult.';
+
+    it('matches computeMetrics with the default thresholds', () => {
+      const metrics = computeMetricsWithThresholds(text, DEFAULT_CEFR_THRESHOLDS);
+      expect(metrics).toEqual(computeMetrics(text));
+    });
+
+    it('applies recalibrated boundaries', () => {
+      const boundaries = { A1: 96, A2: 97, B1: 98, B2: 99, C1: 99.5 };
+      const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, boundaries };
+      expect(computeMetricsWithThresholds(text, thresholds).cefr_level).toBe('A1');
+    });
+
+    it('marks transcripts below the minimum word count as unreliable', () => {
+      expect(computeMetrics(text).cefr_is_reliable).toBe(true);
+      const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, minWords: 20 };
+      const metrics = computeMetricsWithThresholds(text, thresholds);
+      expect(metrics).toMatchObject({ cefr_is_reliable: false, cefr_confidence: 0 });
+      expect(metrics.cefr_level).toBe(computeMetrics(text).cefr_level);
+    });
+
+    it('rejects thresholds that are not increasing', () => {
+      const boundaries = { A1: 50, A2: 40, B1: 55, B2: 70, C1: 85 };
+      const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, boundaries };
+      expect(() => computeMetricsWithThresholds(text, thresholds)).toThrow();
+    });
+  });
+
+  describe('computeCEFRLevel', () => {
+    it('matches the level reported by computeMetrics', () => {
+      const text =
+        'A fundamental understanding of intricate algorithms is absolutely essential for comprehensive software development.';
+      expect(computeCEFRLevel(text)).toBe(computeMetrics(text).cefr_level);
+    });
+
+    it('scores only the first maxWords words', () => {
+      const text =
+        'I go to the shop. A fundamental understanding of intricate algorithms is essential.';
+      const options = { maxWords: 5 };
+      const level = computeMetrics(text, undefined, options).cefr_level;
+      expect(computeCEFRLevel(text, options)).toBe(level);
+      expect(computeCEFRLevel(text, options)).toBe(computeCEFRLevel('I go to the shop.'));
+    });
+
+    it('returns A1 for text too short to assess when asked to', () => {
+      expect(computeCEFRLevel('Hello there', { forceA1WhenShort: true })).toBe('A1');
+    });
+  });
+});
@@ -0,0 +1,75 @@
+import { computeMetrics } from './metrics-calculator';
+import { scoreTranscript } from './cefr-transcript';
+import { analysisWords, capWords, prepareText, resolveOptions } from './metrics-options';
+import { splitSentences } from './sentence-splitter';
+import { normalizeVocabulary } from './vocabulary';
+import type { CefrThresholds } from './cefr-thresholds';
+import type { Metrics, MetricsOptions, WordScores } from './metrics-types';
+
+const UTF8_DECODER = new TextDecoder('utf-8', { fatal: true });
+
+/**
+ * Computes metrics for UTF-8 encoded text, such as a request body, decoding it once.
+ * Throws a TypeError when the bytes are not valid UTF-8.
+ */
+export function computeMetricsFromBytes(
+  bytes: Uint8Array,
+  words?: WordScores,
+  metricsOptions: MetricsOptions = {}
+): Metrics {
+  return computeMetrics(UTF8_DECODER.decode(bytes), words, metricsOptions);
+}
+
+export function computeMetricsWithDuration(
+  text: string,
+  durationSecs: number,
+  words?: WordScores
+): Metrics {
+  return computeMetrics(text, words, { durationSecs });
+}
+
+/**
+ * Computes only the heuristic CEFR level, skipping the lexical, readability and
+ * per-sentence metrics that computeMetrics also builds.
+ */
+export function computeCEFRLevel(text: string, metricsOptions: MetricsOptions = {}): string {
+  const options = resolveOptions(text, metricsOptions);
+  const analysisText = prepareText(capWords(text, options).text, options);
+  const textWords = analysisWords(analysisText, options);
+  const sentenceCount = splitSentences(analysisText, options.sentenceSplitMode).length;
+  return scoreTranscript(analysisText, textWords, sentenceCount, options).level;
+}
+
+/**
+ * Computes metrics against a caller-supplied list of familiar words instead of the
+ * built-in list. Matching is case-insensitive and ignores punctuation in both the
+ * transcript and the list.
+ */
+export function computeMetricsWithVocab(
+  text: string,
+  commonWords: Iterable<string>,
+  words?: WordScores
+): Metrics {
+  return computeMetrics(text, words, { commonWords: normalizeVocabulary(commonWords) });
+}
+
+/**
+ * Computes metrics with institution-specific CEFR boundaries and weights.
+ * Throws if the thresholds fail validation.
+ */
+export function computeMetricsWithThresholds(
+  text: string,
+  thresholds: CefrThresholds,
+  words?: WordScores
+): Metrics {
+  return computeMetrics(text, words, { thresholds });
+}
+
+/** Computes metrics flagging a caller-supplied word list, matched case-insensitively. */
+export function computeMetricsWithFlagged(
+  text: string,
+  flaggedWords: Iterable<string>,
+  words?: WordScores
+): Metrics {
+  return computeMetrics(text, words, { flaggedWords: normalizeVocabulary(flaggedWords) });
+}
@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { expandNumbers, spellOutNumber } from './number-words';
+
+describe('number-words', () => {
+  it('spells out integers up to 999', () => {
+    expect(spellOutNumber(0)).toBe('zero');
+    expect(spellOutNumber(15)).toBe('fifteen');
+    expect(spellOutNumber(40)).toBe('forty');
+    expect(spellOutNumber(21)).toBe('twenty-one');
+    expect(spellOutNumber(305)).toBe('three hundred five');
+    expect(spellOutNumber(999)).toBe('nine hundred ninety-nine');
+  });
+
+  it('expands small whole numbers in text', () => {
+    expect(expandNumbers('I ran 5 km in 42 minutes')).toBe('I ran five km in forty-two minutes');
+  });
+
+  it('leaves large numbers and decimals as digits', () => {
+    expect(expandNumbers('It cost 1500 or 3.50 each')).toBe('It cost 1500 or 3.50 each');
+  });
+
+  it('leaves ordinals and numbers joined to units alone', () => {
+    expect(expandNumbers('She came 3rd in the 5km race')).toBe('She came 3rd in the 5km race');
+    expect(expandNumbers('It was the 21st of May, about 5 km away')).toBe(
+      'It was the 21st of May, about five km away'
+    );
+  });
+});
@@ -0,0 +1,35 @@
+export type NumberHandling = 'drop' | 'count' | 'expand';
+
+const MAX_SPELLED_NUMBER = 999;
+const NUMBER_PATTERN = /(?<![\p{L}\p{M}\p{N}])\p{N}+(?:[.,]\p{N}+)*(?![\p{L}\p{M}\p{N}])/gu;
+const ASCII_DIGITS_PATTERN = /^[0-9]+$/;
+
+const ONES = (
+  'zero one two three four five six seven eight nine ten eleven twelve thirteen fourteen ' +
+  'fifteen sixteen seventeen eighteen nineteen'
+).split(' ');
+const TENS = ['', '', 'twenty', 'thirty', 'forty', 'fifty', 'sixty', 'seventy', 'eighty', 'ninety'];
+
+/** English words for an integer from 0 to 999, e.g. "one hundred twenty-three". */
+export function spellOutNumber(value: number): string {
+  if (value < 20) return ONES[value];
+  if (value < 100) {
+    const rest = value % 10;
+    return TENS[Math.floor(value / 10)] + (rest ? `-${ONES[rest]}` : '');
+  }
+  const rest = value % 100;
+  return `${ONES[Math.floor(value / 100)]} hundred${rest ? ` ${spellOutNumber(rest)}` : ''}`;
+}
+
+/**
+ * Spells out standalone whole numbers up to 999 so they are analyzed as words. Larger
+ * numbers, decimals, non-ASCII digits and numbers attached to letters ("3rd", "5km") are
+ * left as they are.
+ */
+export function expandNumbers(text: string): string {
+  return text.replace(NUMBER_PATTERN, (number) => {
+    if (!ASCII_DIGITS_PATTERN.test(number)) return number;
+    const value = Number(number);
+    return value <= MAX_SPELLED_NUMBER ? spellOutNumber(value) : number;
+  });
+}
@@ -0,0 +1,39 @@
+import { describe, it, expect } from 'vitest';
+import { parseTimestamped } from './pause-analysis';
+import { computeMetrics } from './metrics-calculator';
+
+describe('pause-analysis', () => {
+  const words = [
+    { word: 'I', start: 0, end: 0.2 },
+    { word: 'went', start: 0.3, end: 0.6 },
+    { word: 'to', start: 1.6, end: 1.7 },
+    { word: ' the ', start: 1.8, end: 2 },
+    { word: 'shop.', start: 2.8, end: 3.1 },
+  ];
+
+  it('counts gaps above the default threshold as pauses', () => {
+    const transcript = parseTimestamped(words);
+    expect(transcript.pause_count).toBe(2);
+    expect(transcript.total_pause_secs).toBeCloseTo(1.8);
+    expect(transcript.longest_pause_secs).toBeCloseTo(1);
+  });
+
+  it('accepts a custom threshold', () => {
+    expect(parseTimestamped(words, 0.9).pause_count).toBe(1);
+  });
+
+  it('joins the words into a transcript computeMetrics can analyze', () => {
+    const { text } = parseTimestamped(words);
+    expect(text).toBe('I went to the shop.');
+    expect(computeMetrics(text).word_count).toBe(5);
+  });
+
+  it('reports no pauses for empty input', () => {
+    expect(parseTimestamped([])).toEqual({
+      text: '',
+      pause_count: 0,
+      total_pause_secs: 0,
+      longest_pause_secs: 0,
+    });
+  });
+});
@@ -0,0 +1,39 @@
+import type { TranscriptionWord } from './transcriber';
+
+export const DEFAULT_PAUSE_THRESHOLD_SECS = 0.5;
+
+export type TimedWord = Pick<TranscriptionWord, 'word' | 'start' | 'end'>;
+
+export interface TimedTranscript {
+  text: string;
+  pause_count: number;
+  total_pause_secs: number;
+  longest_pause_secs: number;
+}
+
+function pauseGaps(words: TimedWord[], thresholdSecs: number): number[] {
+  const gaps: number[] = [];
+  for (let i = 1; i < words.length; i++) {
+    const gap = words[i].start - words[i - 1].end;
+    if (gap > thresholdSecs) gaps.push(gap);
+  }
+  return gaps;
+}
+
+/**
+ * Joins word-level ASR output into a transcript and summarizes the silences between words.
+ * Only gaps longer than `thresholdSecs` count as pauses.
+ */
+export function parseTimestamped(
+  words: TimedWord[],
+  thresholdSecs = DEFAULT_PAUSE_THRESHOLD_SECS
+): TimedTranscript {
+  const spoken = words.filter((word) => word.word.trim().length > 0);
+  const gaps = pauseGaps(spoken, thresholdSecs);
+  return {
+    text: spoken.map((word) => word.word.trim()).join(' '),
+    pause_count: gaps.length,
+    total_pause_secs: gaps.reduce((total, gap) => total + gap, 0),
+    longest_pause_secs: gaps.length > 0 ? Math.max(...gaps) : 0,
+  };
+}
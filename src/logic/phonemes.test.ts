@@ -0,0 +1,33 @@
+import { describe, it, expect } from 'vitest';
+import { estimatePhonemeCount, estimatePhonemes } from './phonemes';
+
+describe('phonemes', () => {
+  describe('estimatePhonemes', () => {
+    it('counts one sound per letter in simple words', () => {
+      expect(estimatePhonemes('cat')).toBe(3);
+      expect(estimatePhonemes('make')).toBe(3);
+    });
+
+    it('counts digraphs and doubled letters once', () => {
+      expect(estimatePhonemes('the')).toBe(2);
+      expect(estimatePhonemes('happy')).toBe(4);
+      expect(estimatePhonemes('strengths')).toBe(7);
+    });
+
+    it('counts the sounds hidden in qu and x', () => {
+      expect(estimatePhonemes('queue')).toBe(3);
+      expect(estimatePhonemes('box')).toBe(4);
+    });
+
+    it('returns 0 for tokens without letters', () => {
+      expect(estimatePhonemes('')).toBe(0);
+      expect(estimatePhonemes('42')).toBe(0);
+    });
+  });
+
+  describe('estimatePhonemeCount', () => {
+    it('sums estimates across words', () => {
+      expect(estimatePhonemeCount(['the', 'cat', 'queue'])).toBe(8);
+    });
+  });
+});
@@ -0,0 +1,32 @@
+import { countSyllables } from './syllables';
+
+const NON_LETTER_PATTERN = /[^a-z]/g;
+const DOUBLE_CONSONANT_PATTERN = /([bcdfghjklmnpqrstvwxz])\1/g;
+/** Letter pairs usually spoken as a single consonant, as in "think", "ship" or "sing". */
+const DIGRAPH_PATTERN = /ch|sh|th|ph|wh|ng|ck|gh/g;
+const CONSONANT_PATTERN = /[bcdfghjklmnpqrstvwxz]/g;
+
+function consonantSounds(clean: string): number {
+  const folded = clean
+    .replace(/qu/g, 'kw')
+    .replace(DOUBLE_CONSONANT_PATTERN, '$1')
+    .replace(DIGRAPH_PATTERN, 'c');
+  const consonants = folded.match(CONSONANT_PATTERN)?.length ?? 0;
+  const xs = folded.match(/x/g)?.length ?? 0;
+  return consonants + xs;
+}
+
+/**
+ * Estimates the phonemes in an English word as one vowel sound per syllable plus its consonant
+ * sounds. Doubled letters and digraphs such as "th" count once, "x" and "qu" count twice.
+ * This is a spelling heuristic for articulatory load, not a transcription.
+ */
+export function estimatePhonemes(word: string): number {
+  const clean = word.toLowerCase().replace(NON_LETTER_PATTERN, '');
+  if (!clean) return 0;
+  return countSyllables(clean) + consonantSounds(clean);
+}
+
+export function estimatePhonemeCount(words: string[]): number {
+  return words.reduce((sum, word) => sum + estimatePhonemes(word), 0);
+}
@@ -0,0 +1,67 @@
+import { describe, it, expect } from 'vitest';
+import {
+  countAcronyms,
+  isAcronym,
+  isLikelyProperNoun,
+  isSentenceInitial,
+  vocabularyWords,
+} from './proper-nouns';
+
+describe('proper-nouns', () => {
+  describe('isSentenceInitial', () => {
+    it('treats the text start and words after terminators as sentence-initial', () => {
+      const text = 'Hello there. "Great," she said';
+      expect(isSentenceInitial(text, 0)).toBe(true);
+      expect(isSentenceInitial(text, text.indexOf('Great'))).toBe(true);
+      expect(isSentenceInitial(text, text.indexOf('she'))).toBe(false);
+    });
+
+    it('can treat the text start as mid-sentence', () => {
+      expect(isSentenceInitial('Microsoft', 0, false)).toBe(false);
+    });
+  });
+
+  describe('isLikelyProperNoun', () => {
+    it('flags capitalized mid-sentence words only', () => {
+      const text = 'Yesterday I met John';
+      expect(isLikelyProperNoun(text, 'John', 16)).toBe(true);
+      expect(isLikelyProperNoun(text, 'Yesterday', 0)).toBe(false);
+    });
+  });
+
+  describe('vocabularyWords', () => {
+    it('skips likely proper nouns by default', () => {
+      expect(vocabularyWords('Sarah joined Microsoft. Then she left')).toEqual([
+        'sarah',
+        'joined',
+        'then',
+        'she',
+        'left',
+      ]);
+    });
+
+    it('keeps them when proper nouns are counted', () => {
+      expect(vocabularyWords('She joined Microsoft', true)).toEqual(['she', 'joined', 'microsoft']);
+    });
+
+    it('always skips acronyms, even at the start of a sentence', () => {
+      expect(vocabularyWords('UNESCO helps us', true)).toEqual(['helps', 'us']);
+    });
+  });
+
+  describe('isAcronym', () => {
+    it('matches all-caps words of two or more letters', () => {
+      expect(isAcronym('NASA')).toBe(true);
+      expect(isAcronym("FBI's")).toBe(true);
+      expect(isAcronym('I')).toBe(false);
+      expect(isAcronym('Nasa')).toBe(false);
+    });
+  });
+
+  describe('countAcronyms', () => {
+    it('counts acronyms but not sentence-initial capitals', () => {
+      expect(countAcronyms('I work at NASA and the FBI')).toBe(2);
+      expect(countAcronyms('A US citizen told us. Then he left')).toBe(1);
+    });
+  });
+});
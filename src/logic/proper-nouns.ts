@@ -0,0 +1,58 @@
+import { lowercase, tokenize } from './text-utils';
+
+const CAPITALIZED_PATTERN = /^\p{Lu}/u;
+const ACRONYM_PATTERN = /^\p{Lu}{2,}(?:'s)?$/u;
+const OPENING_PUNCTUATION_PATTERN = /[\s"'([{]/u;
+const SENTENCE_TERMINATORS = new Set(['.', '!', '?']);
+
+/**
+ * True when only whitespace and opening punctuation separate `index` from a sentence start.
+ * `startsSentence` says whether the beginning of `text` itself opens a sentence.
+ */
+export function isSentenceInitial(text: string, index: number, startsSentence = true): boolean {
+  let i = index - 1;
+  while (i >= 0 && OPENING_PUNCTUATION_PATTERN.test(text[i])) i--;
+  return i < 0 ? startsSentence : SENTENCE_TERMINATORS.has(text[i]);
+}
+
+/** A capitalized word that does not start a sentence, the usual shape of a proper noun. */
+export function isLikelyProperNoun(
+  text: string,
+  word: string,
+  start: number,
+  startsSentence = true
+): boolean {
+  return CAPITALIZED_PATTERN.test(word) && !isSentenceInitial(text, start, startsSentence);
+}
+
+/**
+ * An all-caps word of two or more letters ("NASA", "FBI's"). Single capitals such as "I" or a
+ * sentence-initial "A" are not acronyms; a shouted all-caps sentence will still match.
+ */
+export function isAcronym(word: string): boolean {
+  return ACRONYM_PATTERN.test(word);
+}
+
+export function countAcronyms(text: string): number {
+  return tokenize(text).filter((token) => isAcronym(token.text)).length;
+}
+
+/**
+ * Words to judge vocabulary on, lowercased with the rules of `locale`. Acronyms are always
+ * skipped and, unless `countProperNouns` is set, so are capitalized mid-sentence words, since
+ * names say little about vocabulary range.
+ */
+export function vocabularyWords(
+  text: string,
+  countProperNouns = false,
+  startsSentence = true,
+  locale?: string
+): string[] {
+  return tokenize(text)
+    .filter((token) => !isAcronym(token.text))
+    .filter(
+      (token) =>
+        countProperNouns || !isLikelyProperNoun(text, token.text, token.start, startsSentence)
+    )
+    .map((token) => lowercase(token.text, locale));
+}
@@ -0,0 +1,125 @@
+import { describe, it, expect } from 'vitest';
+import {
+  automatedReadabilityIndex,
+  colemanLiau,
+  daleChall,
+  fleschReadingEase,
+  gunningFog,
+  smogGrade,
+  type ReadabilityStats,
+} from './readability';
+
+function makeStats(overrides: Partial<ReadabilityStats> = {}): ReadabilityStats {
+  return {
+    wordCount: 0,
+    sentenceCount: 0,
+    syllableCount: 0,
+    polysyllableCount: 0,
+    letterCount: 0,
+    difficultWordCount: 0,
+    ...overrides,
+  };
+}
+
+describe('readability', () => {
+  describe('fleschReadingEase', () => {
+    it('scores short monosyllabic text as very easy', () => {
+      const score = fleschReadingEase(
+        makeStats({ wordCount: 4, sentenceCount: 1, syllableCount: 4 })
+      );
+      expect(score).toBeCloseTo(118.175, 3);
+    });
+
+    it('scores long polysyllabic text as difficult', () => {
+      const score = fleschReadingEase(
+        makeStats({ wordCount: 30, sentenceCount: 1, syllableCount: 60 })
+      );
+      expect(score).toBeLessThan(30);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(fleschReadingEase(makeStats())).toBe(0);
+    });
+  });
+
+  describe('gunningFog', () => {
+    it('combines sentence length and polysyllable share', () => {
+      const score = gunningFog(
+        makeStats({ wordCount: 20, sentenceCount: 2, polysyllableCount: 2 })
+      );
+      expect(score).toBeCloseTo(8);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(gunningFog(makeStats())).toBe(0);
+    });
+  });
+
+  describe('smogGrade', () => {
+    it('scales polysyllables to a 30-sentence sample', () => {
+      const grade = smogGrade(
+        makeStats({ wordCount: 100, sentenceCount: 30, polysyllableCount: 9 })
+      );
+      expect(grade).toBeCloseTo(1.043 * 3 + 3.1291, 4);
+    });
+
+    it('returns the base grade when there are no polysyllables', () => {
+      expect(smogGrade(makeStats({ wordCount: 5, sentenceCount: 1 }))).toBeCloseTo(3.1291, 4);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(smogGrade(makeStats())).toBe(0);
+    });
+  });
+
+  describe('colemanLiau', () => {
+    it('combines letters and sentences per 100 words', () => {
+      const index = colemanLiau(makeStats({ wordCount: 100, sentenceCount: 5, letterCount: 450 }));
+      expect(index).toBeCloseTo(0.0588 * 450 - 0.296 * 5 - 15.8, 4);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(colemanLiau(makeStats())).toBe(0);
+    });
+  });
+
+  describe('daleChall', () => {
+    it('omits the penalty at or below 5% difficult words', () => {
+      const score = daleChall(
+        makeStats({ wordCount: 100, sentenceCount: 10, difficultWordCount: 5 })
+      );
+      expect(score).toBeCloseTo(0.1579 * 5 + 0.0496 * 10, 4);
+    });
+
+    it('adds the penalty above 5% difficult words', () => {
+      const score = daleChall(
+        makeStats({ wordCount: 100, sentenceCount: 10, difficultWordCount: 6 })
+      );
+      expect(score).toBeCloseTo(0.1579 * 6 + 0.0496 * 10 + 3.6365, 4);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(daleChall(makeStats())).toBe(0);
+    });
+  });
+
+  describe('automatedReadabilityIndex', () => {
+    it('combines characters per word and words per sentence', () => {
+      const index = automatedReadabilityIndex(
+        makeStats({ wordCount: 20, sentenceCount: 1, letterCount: 120 })
+      );
+      expect(index).toBeCloseTo(4.71 * 6 + 0.5 * 20 - 21.43, 4);
+    });
+
+    it('clamps very simple text to 0', () => {
+      const index = automatedReadabilityIndex(
+        makeStats({ wordCount: 3, sentenceCount: 1, letterCount: 6 })
+      );
+      expect(index).toBe(0);
+    });
+
+    it('returns 0 for empty input', () => {
+      expect(automatedReadabilityIndex(makeStats())).toBe(0);
+    });
+  });
+});
@@ -0,0 +1,114 @@
+export interface ReadabilityStats {
+  wordCount: number;
+  sentenceCount: number;
+  syllableCount: number;
+  polysyllableCount: number;
+  letterCount: number;
+  difficultWordCount: number;
+}
+
+const FLESCH_BASE = 206.835;
+const FLESCH_SENTENCE_WEIGHT = 1.015;
+const FLESCH_SYLLABLE_WEIGHT = 84.6;
+const FOG_WEIGHT = 0.4;
+const PERCENT = 100;
+const SMOG_WEIGHT = 1.043;
+const SMOG_BASE = 3.1291;
+const SMOG_SAMPLE_SENTENCES = 30;
+const COLEMAN_LIAU_LETTER_WEIGHT = 0.0588;
+const COLEMAN_LIAU_SENTENCE_WEIGHT = 0.296;
+const COLEMAN_LIAU_BASE = 15.8;
+const ARI_CHARACTER_WEIGHT = 4.71;
+const ARI_SENTENCE_WEIGHT = 0.5;
+const ARI_BASE = 21.43;
+const DALE_CHALL_DIFFICULT_WEIGHT = 0.1579;
+const DALE_CHALL_SENTENCE_WEIGHT = 0.0496;
+const DALE_CHALL_PENALTY = 3.6365;
+const DALE_CHALL_PENALTY_PERCENT = 5;
+
+function hasText(stats: ReadabilityStats): boolean {
+  return stats.wordCount > 0 && stats.sentenceCount > 0;
+}
+
+/**
+ * Flesch Reading Ease: higher scores mean easier text (90+ very easy, below 30 very difficult).
+ * Returns 0 for empty input.
+ */
+export function fleschReadingEase(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const wordsPerSentence = stats.wordCount / stats.sentenceCount;
+  const syllablesPerWord = stats.syllableCount / stats.wordCount;
+  return (
+    FLESCH_BASE -
+    FLESCH_SENTENCE_WEIGHT * wordsPerSentence -
+    FLESCH_SYLLABLE_WEIGHT * syllablesPerWord
+  );
+}
+
+/**
+ * Gunning Fog index: years of formal education needed to follow the text on first reading.
+ * "Complex" words here are those of three or more syllables. Returns 0 for empty input.
+ */
+export function gunningFog(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const wordsPerSentence = stats.wordCount / stats.sentenceCount;
+  const polysyllablePercent = (PERCENT * stats.polysyllableCount) / stats.wordCount;
+  return FOG_WEIGHT * (wordsPerSentence + polysyllablePercent);
+}
+
+/**
+ * SMOG grade, scaled to a 30-sentence sample. The formula was calibrated on at least
+ * 30 sentences, so scores for shorter transcripts are unstable; results are clamped
+ * to a minimum of 0 and empty input returns 0.
+ */
+export function smogGrade(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const sampleScale = SMOG_SAMPLE_SENTENCES / stats.sentenceCount;
+  const scaledPolysyllables = stats.polysyllableCount * sampleScale;
+  return Math.max(0, SMOG_WEIGHT * Math.sqrt(scaledPolysyllables) + SMOG_BASE);
+}
+
+/**
+ * Coleman-Liau index: a grade level from letters and sentences per 100 words, so it does
+ * not depend on syllable estimates. Returns 0 for empty input.
+ */
+export function colemanLiau(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const lettersPer100 = (PERCENT * stats.letterCount) / stats.wordCount;
+  const sentencesPer100 = (PERCENT * stats.sentenceCount) / stats.wordCount;
+  return (
+    COLEMAN_LIAU_LETTER_WEIGHT * lettersPer100 -
+    COLEMAN_LIAU_SENTENCE_WEIGHT * sentencesPer100 -
+    COLEMAN_LIAU_BASE
+  );
+}
+
+/**
+ * Dale-Chall score. Difficult words are the complex-word count: unfamiliar words that are
+ * also long or carry an academic suffix, a stricter test than the original "not on the
+ * familiar-word list". Adds the standard penalty once more than 5% of words are difficult.
+ * Returns 0 for empty input.
+ */
+export function daleChall(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const difficultPercent = (PERCENT * stats.difficultWordCount) / stats.wordCount;
+  const wordsPerSentence = stats.wordCount / stats.sentenceCount;
+  const penalty = difficultPercent > DALE_CHALL_PENALTY_PERCENT ? DALE_CHALL_PENALTY : 0;
+  return (
+    DALE_CHALL_DIFFICULT_WEIGHT * difficultPercent +
+    DALE_CHALL_SENTENCE_WEIGHT * wordsPerSentence +
+    penalty
+  );
+}
+
+/**
+ * Automated Readability Index from characters per word and words per sentence. Characters
+ * are the letters of each word token. Clamped to a minimum of 0; empty input returns 0.
+ */
+export function automatedReadabilityIndex(stats: ReadabilityStats): number {
+  if (!hasText(stats)) return 0;
+  const charactersPerWord = stats.letterCount / stats.wordCount;
+  const wordsPerSentence = stats.wordCount / stats.sentenceCount;
+  const index = ARI_CHARACTER_WEIGHT * charactersPerWord + ARI_SENTENCE_WEIGHT * wordsPerSentence;
+  return Math.max(0, index - ARI_BASE);
+}
@@ -0,0 +1,62 @@
+import { describe, it, expect } from 'vitest';
+import { detectRepairs, stripRepairs, topRepeatedBigrams } from './repetition';
+
+describe('repetition', () => {
+  describe('topRepeatedBigrams', () => {
+    it('lists pairs that occur at least twice', () => {
+      const result = topRepeatedBigrams(['I think and then I think and then we left']);
+      expect(result).toEqual([
+        { bigram: 'and then', count: 2 },
+        { bigram: 'i think', count: 2 },
+        { bigram: 'think and', count: 2 },
+      ]);
+    });
+
+    it('orders by count then alphabetically', () => {
+      const result = topRepeatedBigrams(['b c b c b c a b a b']);
+      expect(result.map((r) => r.bigram)).toEqual(['b c', 'a b', 'c b']);
+    });
+
+    it('caps the list at five entries', () => {
+      const sentence = 'a b c d e f g h a b c d e f g h';
+      expect(topRepeatedBigrams([sentence])).toHaveLength(5);
+    });
+
+    it('does not pair words across sentences', () => {
+      const sentences = ['It was the end', 'Start again', 'The end', 'Start now'];
+      expect(topRepeatedBigrams(sentences)).toEqual([{ bigram: 'the end', count: 2 }]);
+    });
+
+    it('returns an empty list when nothing repeats', () => {
+      expect(topRepeatedBigrams(['the quick brown fox'])).toEqual([]);
+    });
+  });
+
+  describe('detectRepairs', () => {
+    it('counts each run of repeated words once', () => {
+      expect(detectRepairs(['i', 'i', 'i', 'went'])).toEqual({ count: 1, tokens: 2 });
+    });
+
+    it('counts separate runs separately', () => {
+      expect(detectRepairs(['the', 'the', 'big', 'big', 'dog'])).toEqual({ count: 2, tokens: 2 });
+    });
+
+    it('finds nothing without adjacent repeats', () => {
+      expect(detectRepairs(['i', 'went', 'i'])).toEqual({ count: 0, tokens: 0 });
+    });
+  });
+
+  describe('stripRepairs', () => {
+    it('keeps the first word of each run', () => {
+      expect(stripRepairs('I I I went to the, the store.')).toBe('I went to the store.');
+    });
+
+    it('matches repeats case-insensitively', () => {
+      expect(stripRepairs('The the end')).toBe('The end');
+    });
+
+    it('leaves text without repairs unchanged', () => {
+      expect(stripRepairs('No repairs here')).toBe('No repairs here');
+    });
+  });
+});
@@ -0,0 +1,78 @@
+import { extractWords, tokenize } from './text-utils';
+
+export interface RepeatedBigram {
+  bigram: string;
+  count: number;
+}
+
+export interface RepairStats {
+  count: number;
+  tokens: number;
+}
+
+const MIN_BIGRAM_REPEATS = 2;
+const MAX_REPEATED_BIGRAMS = 5;
+
+function countBigrams(sentences: string[]): Map<string, number> {
+  const counts = new Map<string, number>();
+  for (const sentence of sentences) {
+    const words = extractWords(sentence);
+    for (let i = 1; i < words.length; i++) {
+      const bigram = `${words[i - 1]} ${words[i]}`;
+      counts.set(bigram, (counts.get(bigram) ?? 0) + 1);
+    }
+  }
+  return counts;
+}
+
+function compareBigrams(a: RepeatedBigram, b: RepeatedBigram): number {
+  if (a.count !== b.count) return b.count - a.count;
+  return a.bigram < b.bigram ? -1 : a.bigram > b.bigram ? 1 : 0;
+}
+
+/**
+ * Most frequent adjacent word pairs that occur at least twice, such as "and then".
+ * Pairs never span a sentence boundary; ties are broken alphabetically.
+ */
+export function topRepeatedBigrams(
+  sentences: string[],
+  limit = MAX_REPEATED_BIGRAMS
+): RepeatedBigram[] {
+  return [...countBigrams(sentences)]
+    .map(([bigram, count]) => ({ bigram, count }))
+    .filter(({ count }) => count >= MIN_BIGRAM_REPEATS)
+    .sort(compareBigrams)
+    .slice(0, limit);
+}
+
+/**
+ * Counts stutter-like repairs where a word is immediately repeated ("the the store").
+ * A run such as "I I I" is one repair event with two repaired tokens.
+ */
+export function detectRepairs(words: string[]): RepairStats {
+  let count = 0;
+  let tokens = 0;
+  for (let i = 1; i < words.length; i++) {
+    if (words[i] !== words[i - 1]) continue;
+    tokens++;
+    if (words[i - 1] !== words[i - 2]) count++;
+  }
+  return { count, tokens };
+}
+
+/** Removes immediately repeated words, keeping the first of each run and its casing. */
+export function stripRepairs(text: string): string {
+  let result = '';
+  let copied = 0;
+  let previous = '';
+  let previousEnd = 0;
+  for (const { text: word, end } of tokenize(text)) {
+    const normalized = word.toLowerCase();
+    if (normalized === previous) {
+      result += text.slice(copied, previousEnd);
+      copied = end;
+    }
+    [previous, previousEnd] = [normalized, end];
+  }
+  return result + text.slice(copied);
+}
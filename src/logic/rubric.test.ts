@@ -0,0 +1,51 @@
+import { describe, it, expect } from 'vitest';
+import { DEFAULT_CEFR_THRESHOLDS } from './cefr-thresholds';
+import { computeMetrics } from './metrics-calculator';
+import { grade, scoreFluency, scoreGrammar, scoreLength, scoreVocabulary } from './rubric';
+import type { Metrics } from './metrics-types';
+
+function metricsWith(overrides: Partial<Metrics>): Metrics {
+  return { ...computeMetrics(''), ...overrides };
+}
+
+describe('rubric', () => {
+  it('scores fluency from pace, fillers and repairs only', () => {
+    const breakdown = { fillers: 100, pace: 50, repairs: 100, diversity: 0 };
+    expect(scoreFluency(metricsWith({ fluency_breakdown: breakdown }))).toBe(4);
+    expect(scoreFluency(metricsWith({ fluency_breakdown: {} }))).toBe(0);
+  });
+
+  it('scores vocabulary from diversity and the complex-word ratio', () => {
+    expect(scoreVocabulary(metricsWith({ word_count: 100, mtld: 60, complex_words: 10 }))).toBe(5);
+    expect(scoreVocabulary(metricsWith({ word_count: 100, mtld: 30, complex_words: 0 }))).toBe(1);
+    expect(scoreVocabulary(metricsWith({ word_count: 0 }))).toBe(0);
+  });
+
+  it('scores grammar from sentence length and grammar clarity', () => {
+    expect(scoreGrammar(metricsWith({ avg_sentence_length: 6, cefr_grammar_score: 20 }))).toBe(4);
+    expect(scoreGrammar(metricsWith({ avg_sentence_length: 30, cefr_grammar_score: 20 }))).toBe(5);
+    expect(scoreGrammar(metricsWith({ avg_sentence_length: 6, cefr_grammar_score: 0 }))).toBe(1);
+  });
+
+  it('scores against the thresholds the metrics were computed with', () => {
+    const thresholds = { ...DEFAULT_CEFR_THRESHOLDS, maxSentenceLength: 6, grammarWeight: 10 };
+    const metrics = metricsWith({ avg_sentence_length: 6, cefr_grammar_score: 10 });
+    expect(scoreGrammar(metrics)).toBe(3);
+    expect(scoreGrammar(metrics, thresholds)).toBe(5);
+    expect(grade(metrics, thresholds).grammar).toBe(5);
+  });
+
+  it('scores length against a 150-word target', () => {
+    expect(scoreLength(metricsWith({ word_count: 60 }))).toBe(2);
+    expect(scoreLength(metricsWith({ word_count: 400 }))).toBe(5);
+  });
+
+  it('grades every dimension', () => {
+    expect(grade(computeMetrics(''))).toEqual({ fluency: 0, vocabulary: 0, grammar: 0, length: 0 });
+    const rubric = grade(computeMetrics('I like my school. My teacher is very kind to us.'));
+    for (const score of Object.values(rubric)) {
+      expect(score).toBeGreaterThanOrEqual(0);
+      expect(score).toBeLessThanOrEqual(5);
+    }
+  });
+});
@@ -0,0 +1,72 @@
+import { DEFAULT_CEFR_THRESHOLDS, type CefrThresholds } from './cefr-thresholds';
+import { fluencyScore, TARGET_MTLD } from './fluency';
+import type { Metrics } from './metrics-types';
+
+/** Whole-band 0-5 sub-scores for common speaking rubric dimensions. */
+export interface RubricScore {
+  fluency: number;
+  vocabulary: number;
+  grammar: number;
+  length: number;
+}
+
+export const RUBRIC_MAX_BAND = 5;
+
+/** Words needed for the full length band. */
+const TARGET_WORD_COUNT = 150;
+
+/** Maps a 0-1 fraction onto the nearest band. */
+function band(fraction: number): number {
+  return Math.round(Math.min(Math.max(fraction, 0), 1) * RUBRIC_MAX_BAND);
+}
+
+/** Pace, filler and repair components of fluency_breakdown; lexical diversity is left out. */
+export function scoreFluency(metrics: Metrics): number {
+  const { fillers, pace, repairs } = metrics.fluency_breakdown;
+  return band(fluencyScore({ fillers, pace, repairs }) / 100);
+}
+
+/** Equal parts MTLD (full at 60) and complex-word ratio (full at the CEFR maxComplexRatio). */
+export function scoreVocabulary(
+  metrics: Metrics,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): number {
+  if (metrics.word_count === 0) return 0;
+  const diversity = Math.min(metrics.mtld / TARGET_MTLD, 1);
+  const complexRatio = metrics.complex_words / metrics.word_count;
+  const complexity = Math.min(complexRatio / thresholds.maxComplexRatio, 1);
+  return band((diversity + complexity) / 2);
+}
+
+/**
+ * Equal parts range (average sentence length, full at the CEFR maxSentenceLength) and accuracy
+ * (cefr_grammar_score, the grammar-clarity points, full at the grammarWeight). Pass the
+ * thresholds the metrics were computed with.
+ */
+export function scoreGrammar(
+  metrics: Metrics,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): number {
+  const { maxSentenceLength, grammarWeight } = thresholds;
+  const range = Math.min(metrics.avg_sentence_length / maxSentenceLength, 1);
+  const accuracy = Math.min(metrics.cefr_grammar_score / grammarWeight, 1);
+  return band((range + accuracy) / 2);
+}
+
+/** Word count, full at 150 words. */
+export function scoreLength(metrics: Metrics): number {
+  return band(metrics.word_count / TARGET_WORD_COUNT);
+}
+
+/** Grades every dimension against the thresholds the metrics were computed with. */
+export function grade(
+  metrics: Metrics,
+  thresholds: CefrThresholds = DEFAULT_CEFR_THRESHOLDS
+): RubricScore {
+  return {
+    fluency: scoreFluency(metrics),
+    vocabulary: scoreVocabulary(metrics, thresholds),
+    grammar: scoreGrammar(metrics, thresholds),
+    length: scoreLength(metrics),
+  };
+}
@@ -0,0 +1,70 @@
+import { describe, it, expect } from 'vitest';
+import { splitSentences } from './sentence-splitter';
+
+describe('sentence-splitter', () => {
+  it('splits on terminal punctuation and trims whitespace', () => {
+    expect(splitSentences('Hello there.  How are you? Great!')).toEqual([
+      'Hello there',
+      'How are you',
+      'Great',
+    ]);
+  });
+
+  it('splits on the Devanagari danda and the Arabic question mark', () => {
+    expect(splitSentences('मैं घर गया। वह स्कूल गई।')).toEqual(['मैं घर गया', 'वह स्कूल गई']);
+    expect(splitSentences('هل أنت بخير؟ نعم')).toEqual(['هل أنت بخير', 'نعم']);
+  });
+
+  it('ignores runs of terminators', () => {
+    expect(splitSentences('Wait... what?!')).toEqual(['Wait', 'what']);
+  });
+
+  it('does not split after known abbreviations', () => {
+    expect(splitSentences('Mr. Jones paid $3.50.')).toEqual(['Mr. Jones paid $3.50']);
+    expect(splitSentences('I met Dr. Smith. She was kind.')).toEqual([
+      'I met Dr. Smith',
+      'She was kind',
+    ]);
+  });
+
+  it('does not split decimals', () => {
+    expect(splitSentences('Pi is roughly 3.14 and e is 2.72.')).toEqual([
+      'Pi is roughly 3.14 and e is 2.72',
+    ]);
+  });
+
+  it('still splits after words that merely end like an abbreviation', () => {
+    expect(splitSentences('I saw a drummer. It was loud.')).toHaveLength(2);
+  });
+
+  it('returns text without punctuation as one sentence', () => {
+    expect(splitSentences('i went home')).toEqual(['i went home']);
+  });
+
+  it('returns no sentences for empty or punctuation-only input', () => {
+    expect(splitSentences('')).toEqual([]);
+    expect(splitSentences(' ... ')).toEqual([]);
+    expect(splitSentences('Hi. , ; ! 👋')).toEqual(['Hi']);
+  });
+
+  describe('heuristic mode', () => {
+    it('breaks before a capitalized word that follows a lowercase word', () => {
+      expect(splitSentences('I went to the store I bought milk', 'heuristic')).toEqual([
+        'I went to the store',
+        'I bought milk',
+      ]);
+    });
+
+    it('still splits on punctuation', () => {
+      expect(splitSentences('It rained. we stayed In', 'heuristic')).toEqual([
+        'It rained',
+        'we stayed',
+        'In',
+      ]);
+    });
+
+    it('is not used by default', () => {
+      expect(splitSentences('I went to the store I bought milk')).toHaveLength(1);
+    });
+  });
+});
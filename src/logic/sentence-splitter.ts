@@ -0,0 +1,68 @@
+/** Includes full-width CJK stops, the Arabic question mark and the Devanagari danda. */
+const SENTENCE_TERMINATORS = new Set(['.', '!', '?', '。', '！', '？', '؟', '।', '॥']);
+const ABBREVIATIONS = new Set(['mr', 'mrs', 'ms', 'dr', 'prof', 'st', 'jr', 'sr', 'vs', 'etc']);
+const MAX_ABBREVIATION_LENGTH = 4;
+const TRAILING_WORD_PATTERN = /\p{L}+$/u;
+const DIGIT_PATTERN = /\d/;
+const LOWERCASE_PATTERN = /\p{Ll}/u;
+const UPPERCASE_PATTERN = /\p{Lu}/u;
+const WHITESPACE_PATTERN = /\s/;
+const CONTENT_PATTERN = /[\p{L}\p{N}]/u;
+
+/**
+ * 'strict' splits only on terminal punctuation. 'heuristic' also breaks where a capitalized
+ * word follows a lowercase word, a best-effort fallback for unpunctuated ASR output that will
+ * also split before proper nouns ("I met John").
+ */
+export type SentenceSplitMode = 'strict' | 'heuristic';
+
+function isDecimalPoint(text: string, index: number): boolean {
+  return (
+    text[index] === '.' &&
+    DIGIT_PATTERN.test(text[index - 1] ?? '') &&
+    DIGIT_PATTERN.test(text[index + 1] ?? '')
+  );
+}
+
+function followsAbbreviation(text: string, index: number): boolean {
+  if (text[index] !== '.') return false;
+  const lookback = text.slice(Math.max(0, index - MAX_ABBREVIATION_LENGTH - 1), index);
+  const match = lookback.match(TRAILING_WORD_PATTERN);
+  return match !== null && ABBREVIATIONS.has(match[0].toLowerCase());
+}
+
+function isSentenceBoundary(text: string, index: number): boolean {
+  return (
+    SENTENCE_TERMINATORS.has(text[index]) &&
+    !isDecimalPoint(text, index) &&
+    !followsAbbreviation(text, index)
+  );
+}
+
+function isCapitalizedBreak(text: string, index: number): boolean {
+  return (
+    WHITESPACE_PATTERN.test(text[index]) &&
+    LOWERCASE_PATTERN.test(text[index - 1] ?? '') &&
+    UPPERCASE_PATTERN.test(text[index + 1] ?? '')
+  );
+}
+
+/**
+ * Splits text on terminal punctuation, keeping original casing and trimming whitespace.
+ * Periods after common abbreviations ("Dr.", "etc.") and between digits ("3.50") do not
+ * end a sentence. Fragments without letters or digits (", ;" or an emoji) are dropped.
+ */
+export function splitSentences(text: string, mode: SentenceSplitMode = 'strict'): string[] {
+  const sentences: string[] = [];
+  let start = 0;
+  for (let index = 0; index < text.length; index++) {
+    const isBreak = mode === 'heuristic' && isCapitalizedBreak(text, index);
+    if (!isSentenceBoundary(text, index) && !isBreak) continue;
+    sentences.push(text.slice(start, index));
+    start = index + 1;
+  }
+  sentences.push(text.slice(start));
+  return sentences
+    .map((sentence) => sentence.trim())
+    .filter((sentence) => CONTENT_PATTERN.test(sentence));
+}
@@ -0,0 +1,39 @@
+import { describe, it, expect } from 'vitest';
+import { stem } from './stemming';
+
+describe('stemming', () => {
+  it('strips regular plural and third-person -s', () => {
+    expect(stem('walks')).toBe('walk');
+    expect(stem('boxes')).toBe('box');
+    expect(stem('studies')).toBe('study');
+  });
+
+  it('strips -ed and -ing', () => {
+    expect(stem('walked')).toBe('walk');
+    expect(stem('walking')).toBe('walk');
+    expect(stem('studied')).toBe('study');
+  });
+
+  it('undoubles final consonants', () => {
+    expect(stem('running')).toBe('run');
+    expect(stem('stopped')).toBe('stop');
+  });
+
+  it('restores a silent e on short stems', () => {
+    expect(stem('making')).toBe('make');
+    expect(stem('hoped')).toBe('hope');
+    expect(stem('created')).toBe('create');
+  });
+
+  it('leaves words that only look suffixed alone', () => {
+    expect(stem('class')).toBe('class');
+    expect(stem('bus')).toBe('bus');
+    expect(stem('thing')).toBe('thing');
+    expect(stem('need')).toBe('need');
+  });
+
+  it('leaves irregular forms unchanged', () => {
+    expect(stem('ran')).toBe('ran');
+    expect(stem('went')).toBe('went');
+  });
+});
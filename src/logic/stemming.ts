@@ -0,0 +1,46 @@
+const VOWEL_PATTERN = /[aeiouy]/;
+const VOWEL_GROUP_PATTERN = /[aeiouy]+/g;
+const SIBILANT_ES_PATTERN = /(s|x|z|ch|sh)es$/;
+const KEEP_FINAL_S_PATTERN = /(ss|us|is)$/;
+const DOUBLE_CONSONANT_PATTERN = /([^aeiouylsz])\1$/;
+const SHORT_CVC_PATTERN = /[^aeiouy][aeiouy][^aeiouywx]$/;
+const RESTORE_E_PATTERN = /(at|bl|iz)$/;
+const MIN_STEM_LENGTH = 3;
+
+function stemPlural(word: string): string {
+  if (word.endsWith('sses')) return word.slice(0, -2);
+  if (word.endsWith('ies')) return word.slice(0, -3) + 'y';
+  if (SIBILANT_ES_PATTERN.test(word)) return word.slice(0, -2);
+  if (word.endsWith('s') && !KEEP_FINAL_S_PATTERN.test(word)) return word.slice(0, -1);
+  return word;
+}
+
+function tidyStem(stem: string): string {
+  if (DOUBLE_CONSONANT_PATTERN.test(stem)) return stem.slice(0, -1);
+  if (RESTORE_E_PATTERN.test(stem)) return stem + 'e';
+  const isSingleSyllable = (stem.match(VOWEL_GROUP_PATTERN) || []).length === 1;
+  if (isSingleSyllable && SHORT_CVC_PATTERN.test(stem)) return stem + 'e';
+  return stem;
+}
+
+function stemVerbSuffix(word: string): string {
+  if (word.endsWith('ied')) return word.slice(0, -3) + 'y';
+  if (word.endsWith('eed')) return word;
+  for (const suffix of ['ing', 'ed']) {
+    if (!word.endsWith(suffix)) continue;
+    const stem = word.slice(0, -suffix.length);
+    return VOWEL_PATTERN.test(stem) ? tidyStem(stem) : word;
+  }
+  return word;
+}
+
+/**
+ * Lightweight Porter-style stemmer for regular English -s, -ed and -ing endings.
+ * Irregular forms ("ran", "went") are returned unchanged.
+ * @param word A lowercase word
+ * @returns The stem, e.g. "walking" -> "walk", "making" -> "make"
+ */
+export function stem(word: string): string {
+  if (word.length <= MIN_STEM_LENGTH) return word;
+  return stemVerbSuffix(stemPlural(word));
+}
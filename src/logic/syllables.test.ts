@@ -0,0 +1,57 @@
+import { describe, it, expect } from 'vitest';
+import { countPolysyllables, countSyllables, countTotalSyllables } from './syllables';
+
+describe('syllables', () => {
+  describe('countSyllables', () => {
+    it('counts simple words', () => {
+      expect(countSyllables('cat')).toBe(1);
+      expect(countSyllables('happy')).toBe(2);
+      expect(countSyllables('beautiful')).toBe(3);
+    });
+
+    it('discounts a silent trailing e', () => {
+      expect(countSyllables('make')).toBe(1);
+      expect(countSyllables('the')).toBe(1);
+    });
+
+    it('keeps the syllable in a consonant + le ending', () => {
+      expect(countSyllables('table')).toBe(2);
+      expect(countSyllables('simple')).toBe(2);
+    });
+
+    it('treats a single vowel run as one syllable', () => {
+      expect(countSyllables('queue')).toBe(1);
+    });
+
+    it('splits a final ea after a consonant', () => {
+      expect(countSyllables('area')).toBe(3);
+      expect(countSyllables('idea')).toBe(3);
+      expect(countSyllables('tea')).toBe(1);
+    });
+
+    it('floors all-consonant tokens at one syllable', () => {
+      expect(countSyllables('hmm')).toBe(1);
+    });
+
+    it('ignores case and punctuation', () => {
+      expect(countSyllables('Table!')).toBe(2);
+    });
+
+    it('returns 0 for tokens without letters', () => {
+      expect(countSyllables('')).toBe(0);
+      expect(countSyllables('42')).toBe(0);
+    });
+  });
+
+  describe('countTotalSyllables', () => {
+    it('sums syllables across words', () => {
+      expect(countTotalSyllables(['the', 'beautiful', 'area'])).toBe(7);
+    });
+  });
+
+  describe('countPolysyllables', () => {
+    it('counts words with three or more syllables', () => {
+      expect(countPolysyllables(['the', 'beautiful', 'area', 'happy'])).toBe(2);
+    });
+  });
+});
@@ -0,0 +1,34 @@
+const VOWEL_GROUP_PATTERN = /[aeiouy]+/g;
+const NON_LETTER_PATTERN = /[^a-z]/g;
+const SILENT_E_PATTERN = /[^aeiouy]e$/;
+const CONSONANT_LE_PATTERN = /[^aeiouy]le$/;
+const FINAL_HIATUS_PATTERN = /[^aeiouy]ea$/;
+const POLYSYLLABLE_MIN_SYLLABLES = 3;
+
+/**
+ * Estimates syllables in an English word by counting vowel groups.
+ * Silent trailing "e" is discounted and every word with letters counts at least once.
+ * @param word The word to analyze (case and punctuation are ignored)
+ * @returns Estimated syllable count, or 0 if the word has no letters
+ */
+export function countSyllables(word: string): number {
+  const clean = word.toLowerCase().replace(NON_LETTER_PATTERN, '');
+  if (!clean) return 0;
+
+  let count = (clean.match(VOWEL_GROUP_PATTERN) || []).length;
+  if (SILENT_E_PATTERN.test(clean) && !CONSONANT_LE_PATTERN.test(clean)) count--;
+  if (count > 1 && FINAL_HIATUS_PATTERN.test(clean)) count++;
+
+  return Math.max(count, 1);
+}
+
+export function countTotalSyllables(words: string[]): number {
+  return words.reduce((sum, word) => sum + countSyllables(word), 0);
+}
+
+/**
+ * Counts words with three or more syllables, as used by the Gunning Fog and SMOG formulas.
+ */
+export function countPolysyllables(words: string[]): number {
+  return words.filter((word) => countSyllables(word) >= POLYSYLLABLE_MIN_SYLLABLES).length;
+}
@@ -0,0 +1,24 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetrics } from './metrics-calculator';
+import { targetWordsUsed } from './target-words';
+
+describe('targetWordsUsed', () => {
+  const transcript = 'I walked to the market. Walking is good, and I bought two apples.';
+
+  it('counts inflected uses of each target', () => {
+    expect(targetWordsUsed(transcript, ['walk', 'Apple', 'umbrella'])).toEqual({
+      counts: { walk: 2, Apple: 1, umbrella: 0 },
+      used: ['walk', 'Apple'],
+      missing: ['umbrella'],
+    });
+  });
+
+  it('accepts computed metrics instead of text', () => {
+    const usage = targetWordsUsed(computeMetrics(transcript), ['market', 'buy']);
+    expect(usage.counts).toEqual({ market: 1, buy: 0 });
+  });
+
+  it('reports every target as missing for an empty transcript', () => {
+    expect(targetWordsUsed('', ['walk']).missing).toEqual(['walk']);
+  });
+});
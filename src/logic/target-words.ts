@@ -0,0 +1,47 @@
+import { wordFrequencies } from './lexical-diversity';
+import { stem } from './stemming';
+import { extractWords } from './text-utils';
+import type { Metrics } from './metrics-types';
+
+export interface TargetUsage {
+  /** Occurrences of each target, keyed by the target as given. */
+  counts: Record<string, number>;
+  /** Targets used at least once, in the order given. */
+  used: string[];
+  /** Targets never used, in the order given. */
+  missing: string[];
+}
+
+const NON_LETTER_PATTERN = /[^\p{L}\p{M}]/gu;
+
+function normalizedStem(word: string): string {
+  return stem(word.toLowerCase().replace(NON_LETTER_PATTERN, ''));
+}
+
+/** Word counts grouped by stem, so "walked" and "walking" both count towards "walk". */
+function stemCounts(source: string | Metrics): Map<string, number> {
+  const frequencies =
+    typeof source === 'string' ? wordFrequencies(extractWords(source)) : source.word_frequencies;
+  const counts = new Map<string, number>();
+  for (const [word, count] of Object.entries(frequencies)) {
+    const wordStem = normalizedStem(word);
+    counts.set(wordStem, (counts.get(wordStem) ?? 0) + count);
+  }
+  return counts;
+}
+
+/**
+ * Which single-word lesson targets a learner used, matched case-insensitively on their stems.
+ * Accepts either a transcript or the metrics already computed for one.
+ */
+export function targetWordsUsed(source: string | Metrics, targets: string[]): TargetUsage {
+  const counts = stemCounts(source);
+  const usage = Object.fromEntries(
+    targets.map((target) => [target, counts.get(normalizedStem(target)) ?? 0])
+  );
+  return {
+    counts: usage,
+    used: targets.filter((target) => usage[target] > 0),
+    missing: targets.filter((target) => usage[target] === 0),
+  };
+}
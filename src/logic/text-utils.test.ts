@@ -0,0 +1,109 @@
+import { describe, it, expect } from 'vitest';
+import {
+  countLetters,
+  countNumericTokens,
+  extractWords,
+  tokenize,
+  truncateWords,
+} from './text-utils';
+
+describe('text-utils', () => {
+  describe('truncateWords', () => {
+    it('cuts after the last allowed word', () => {
+      const cut = { text: 'One two', truncated: true };
+      expect(truncateWords('One two, three four.', 2)).toEqual(cut);
+      expect(truncateWords("don't stop-gap now", 2)).toEqual({
+        text: "don't stop-gap",
+        truncated: true,
+      });
+    });
+
+    it('finds words as in the normalized text', () => {
+      const kept = { text: 'John’s  here', truncated: false };
+      expect(truncateWords('John’s  here', 2)).toEqual(kept);
+      expect(truncateWords('John’s  here now', 2)).toEqual({ ...kept, truncated: true });
+    });
+
+    it('rounds a fractional cap down', () => {
+      expect(truncateWords('One two three.', 2.5)).toEqual({ text: 'One two', truncated: true });
+    });
+
+    it('counts tokens by the given word counter', () => {
+      const options = { includeNumbers: true, wordsIn: (token: string) => token.length };
+      expect(truncateWords('ab 7 cd', 3, options)).toEqual({ text: 'ab 7', truncated: true });
+    });
+
+    it('leaves text within the cap untouched', () => {
+      expect(truncateWords('One two.', 2)).toEqual({ text: 'One two.', truncated: false });
+      expect(truncateWords('One two three.')).toEqual({ text: 'One two three.', truncated: false });
+    });
+  });
+
+  describe('extractWords', () => {
+    it('lowercases words and keeps internal apostrophes', () => {
+      expect(extractWords("Don't STOP, café!")).toEqual(["don't", 'stop', 'café']);
+    });
+
+    it('counts a hyphenated compound as one word', () => {
+      expect(extractWords('My mother-in-law is well-known')).toHaveLength(4);
+    });
+
+    it('splits Chinese and Japanese into single characters', () => {
+      expect(extractWords('私はコーヒーが好き')).toEqual(['私', 'は', 'コー', 'ヒー', 'が', '好', 'き']);
+      expect(extractWords('Tokyo東京')).toEqual(['tokyo', '東', '京']);
+    });
+
+    it('keeps combining vowel signs inside Hindi and Arabic words', () => {
+      expect(extractWords('नमस्ते, आप कैसे हैं?')).toEqual(['नमस्ते', 'आप', 'कैसे', 'हैं']);
+      expect(extractWords('كِتَابٌ جميل')).toEqual(['كِتَابٌ', 'جميل']);
+    });
+
+    it('includes numbers when asked', () => {
+      expect(extractWords('I ran 5 km', { includeNumbers: true })).toEqual(['i', 'ran', '5', 'km']);
+    });
+
+    it('applies Turkish casing for dotted and dotless i', () => {
+      expect(extractWords('İSTANBUL IRMAK', { locale: 'tr' })).toEqual(['istanbul', 'ırmak']);
+      expect(extractWords('IRMAK')).toEqual(['irmak']);
+      expect(extractWords('İSTANBUL')).toEqual(['i\u0307stanbul']);
+    });
+  });
+
+  describe('tokenize', () => {
+    it('returns original words with their offsets', () => {
+      expect(tokenize('Hi, café!')).toEqual([
+        { text: 'Hi', start: 0, end: 2 },
+        { text: 'café', start: 4, end: 8 },
+      ]);
+    });
+
+    it('keeps internal hyphens and apostrophes but strips surrounding punctuation', () => {
+      expect(tokenize("'My mother-in-law don't-' -ok").map((token) => token.text)).toEqual([
+        'My',
+        'mother-in-law',
+        "don't",
+        'ok',
+      ]);
+    });
+  });
+
+  describe('countLetters', () => {
+    it('counts alphabetic characters only', () => {
+      expect(countLetters(["don't", 'café'])).toBe(8);
+    });
+
+    it('returns 0 for no words', () => {
+      expect(countLetters([])).toBe(0);
+    });
+  });
+
+  describe('countNumericTokens', () => {
+    it('counts integers and decimals as single tokens', () => {
+      expect(countNumericTokens('In 2024 it cost $3.50, or 1,000 yen')).toBe(3);
+    });
+
+    it('returns 0 when there are no numbers', () => {
+      expect(countNumericTokens('no numbers here')).toBe(0);
+    });
+  });
+});
@@ -0,0 +1,100 @@
+import { normalizeTranscriptWithOffsets } from './transcript-normalizer';
+
+/** Han ideographs and kana, which are not separated by spaces, and the kana length mark. */
+const CJK_CHARACTER = '[\\p{Script=Han}\\p{Script=Hiragana}\\p{Script=Katakana}\\u30FC]';
+/** A letter with any combining marks, such as Devanagari vowel signs or Arabic harakat. */
+const NON_CJK_LETTER = `(?:(?!${CJK_CHARACTER})\\p{L}\\p{M}*)`;
+const WORD_PATTERN = new RegExp(
+  `${CJK_CHARACTER}\\u30FC*|${NON_CJK_LETTER}+(?:['-]${NON_CJK_LETTER}+)*`,
+  'gu'
+);
+const LETTER_PATTERN = /\p{L}/gu;
+const NUMBER_PATTERN = /\p{N}+(?:[.,]\p{N}+)*/gu;
+const WORD_OR_NUMBER_PATTERN = new RegExp(`${WORD_PATTERN.source}|${NUMBER_PATTERN.source}`, 'gu');
+
+export interface ExtractOptions {
+  /** Keep numbers such as "42" as words. */
+  includeNumbers?: boolean;
+  /**
+   * BCP 47 locale for case folding, e.g. 'tr' so "I" lowers to dotless "ı" and "İ" to "i".
+   * Defaults to locale-independent Unicode lowercasing.
+   */
+  locale?: string;
+}
+
+export interface TruncateOptions {
+  /** Count numbers such as "42" towards the cap. */
+  includeNumbers?: boolean;
+  /** How many words a token counts for; one each by default. */
+  wordsIn?: (token: string) => number;
+}
+
+export interface Token {
+  text: string;
+  start: number;
+  end: number;
+}
+
+/** Lowercases with the locale's rules when one is given. */
+export function lowercase(text: string, locale?: string): string {
+  return locale ? text.toLocaleLowerCase(locale) : text.toLowerCase();
+}
+
+/**
+ * Lowercased words made of Unicode letters, keeping internal apostrophes ("don't") and
+ * hyphens ("mother-in-law"). Chinese and Japanese are not written with spaces, so each
+ * ideograph or kana is its own token; this is an approximate, dictionary-free segmentation.
+ */
+export function extractWords(text: string, options: ExtractOptions = {}): string[] {
+  const pattern = options.includeNumbers ? WORD_OR_NUMBER_PATTERN : WORD_PATTERN;
+  return lowercase(text, options.locale).match(pattern) || [];
+}
+
+/**
+ * Splits text into word tokens as they appear in the original, with UTF-16 offsets usable
+ * with `slice`. Internal apostrophes and hyphens are kept; surrounding punctuation is not.
+ */
+export function tokenize(text: string, options: ExtractOptions = {}): Token[] {
+  const pattern = options.includeNumbers ? WORD_OR_NUMBER_PATTERN : WORD_PATTERN;
+  return Array.from(text.matchAll(pattern), (match) => ({
+    text: match[0],
+    start: match.index ?? 0,
+    end: (match.index ?? 0) + match[0].length,
+  }));
+}
+
+/**
+ * Cuts text after the last word that fits in `maxWords`, so no word is split; a token worth
+ * more words than are left is dropped whole. Words are found in the normalized text, as
+ * computeMetrics sees it, and the cut is made in the original. Fractional caps round down.
+ * `truncated` reports whether anything was removed. Without a cap the text is returned whole.
+ */
+export function truncateWords(text: string, maxWords?: number, options: TruncateOptions = {}) {
+  if (maxWords === undefined) return { text, truncated: false };
+  const cap = Math.floor(maxWords);
+  const wordsIn = options.wordsIn ?? (() => 1);
+  const normalized = normalizeTranscriptWithOffsets(text);
+  let words = 0;
+  let end = 0;
+  for (const token of tokenize(normalized.text, options)) {
+    words += wordsIn(token.text);
+    if (words > cap) return { text: text.slice(0, end), truncated: true };
+    end = normalized.offsets[token.end - 1] + 1;
+  }
+  return { text, truncated: false };
+}
+
+/** Counts Unicode code points, so accented letters and emoji count once. */
+export function characterCount(text: string): number {
+  return Array.from(text).length;
+}
+
+/** Counts alphabetic code points across words, ignoring apostrophes and hyphens. */
+export function countLetters(words: string[]): number {
+  return words.reduce((total, word) => total + (word.match(LETTER_PATTERN)?.length ?? 0), 0);
+}
+
+/** Counts numbers such as "2024" or "3.50", which the word tokenizer skips. */
+export function countNumericTokens(text: string): number {
+  return text.match(NUMBER_PATTERN)?.length ?? 0;
+}
@@ -0,0 +1,66 @@
+import { describe, it, expect } from 'vitest';
+import { computeMetricsTimed, rateVariability } from './timed-metrics';
+
+describe('timed-metrics', () => {
+  describe('rateVariability', () => {
+    it('is the standard deviation of per-sentence words per minute', () => {
+      const variability = rateVariability([
+        ['one two three four', 2],
+        ['one two', 2],
+      ]);
+      expect(variability).toBeCloseTo(30);
+    });
+
+    it('is zero for an even pace', () => {
+      expect(
+        rateVariability([
+          ['one two', 1],
+          ['three four', 1],
+        ])
+      ).toBe(0);
+    });
+
+    it('is undefined with fewer than two valid durations', () => {
+      expect(rateVariability([['one two', 1]])).toBeUndefined();
+      expect(
+        rateVariability([
+          ['one two', 1],
+          ['three four', 0],
+        ])
+      ).toBeUndefined();
+    });
+  });
+
+  describe('computeMetricsTimed', () => {
+    it('analyzes the joined sentences over their total duration', () => {
+      const metrics = computeMetricsTimed([
+        ['I like cats.', 1],
+        ['Dogs are nice too!', 2],
+      ]);
+      expect(metrics.word_count).toBe(7);
+      expect(metrics.sentence_count).toBe(2);
+      expect(metrics.words_per_minute).toBeCloseTo(140);
+      expect(metrics.rate_variability).toBeCloseTo(30);
+    });
+
+    it('leaves untimed sentences out of the speaking rate', () => {
+      const metrics = computeMetricsTimed([
+        ['I like cats.', 1],
+        ['Dogs are nice too!', 0],
+        ['Birds sing.', 1],
+      ]);
+      expect(metrics.word_count).toBe(9);
+      expect(metrics.words_per_minute).toBeCloseTo(150);
+    });
+
+    it('times only the words within maxWords', () => {
+      const sentences: [string, number][] = [
+        ['I like cats.', 1],
+        ['Dogs are nice too!', 2],
+      ];
+      const metrics = computeMetricsTimed(sentences, { maxWords: 5 });
+      expect(metrics.word_count).toBe(5);
+      expect(metrics.words_per_minute).toBeCloseTo(150);
+    });
+  });
+});
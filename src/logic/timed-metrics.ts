@@ -0,0 +1,85 @@
+import { computeMetrics } from './metrics-calculator';
+import { calculateFluencyMetrics } from './fluency';
+import { calculateWordsPerMinute } from './metric-groups';
+import { capWords, resolveOptions } from './metrics-options';
+import { wordCount } from './word-count';
+import type { Metrics, MetricsOptions } from './metrics-types';
+
+const SECONDS_PER_MINUTE = 60;
+const MIN_TIMED_SENTENCES = 2;
+
+export type TimedSentence = [text: string, durationSecs: number];
+
+function standardDeviation(values: number[]): number {
+  const mean = values.reduce((sum, value) => sum + value, 0) / values.length;
+  const variance = values.reduce((sum, value) => sum + (value - mean) ** 2, 0) / values.length;
+  return Math.sqrt(variance);
+}
+
+function hasValidDuration([, durationSecs]: TimedSentence): boolean {
+  return Number.isFinite(durationSecs) && durationSecs > 0;
+}
+
+/**
+ * Population standard deviation of per-sentence words per minute. Undefined when fewer
+ * than two sentences have a positive duration.
+ */
+export function rateVariability(
+  sentences: TimedSentence[],
+  options: MetricsOptions = {}
+): number | undefined {
+  const timed = sentences.filter(hasValidDuration);
+  if (timed.length < MIN_TIMED_SENTENCES) return undefined;
+  const rates = timed.map(
+    ([text, durationSecs]) => (wordCount(text, options) / durationSecs) * SECONDS_PER_MINUTE
+  );
+  return standardDeviation(rates);
+}
+
+function timedWordCount(timed: TimedSentence[], options: MetricsOptions): number {
+  return timed.reduce((total, [text]) => total + wordCount(text, options), 0);
+}
+
+/**
+ * Keeps the sentences within the maxWords cap, in order. The sentence the cap falls in is cut
+ * and keeps the share of its duration that its remaining words took.
+ */
+function capSentences(sentences: TimedSentence[], options: MetricsOptions): TimedSentence[] {
+  if (options.maxWords === undefined) return sentences;
+  const uncapped = { ...options, maxWords: undefined };
+  const capped: TimedSentence[] = [];
+  let remaining = options.maxWords;
+  for (const [text, durationSecs] of sentences) {
+    const cut = capWords(text, { ...options, maxWords: remaining });
+    const kept = wordCount(cut.text, uncapped);
+    const total = wordCount(text, uncapped);
+    capped.push([cut.text, total > 0 ? (durationSecs * kept) / total : durationSecs]);
+    if (cut.truncated) break;
+    remaining -= kept;
+  }
+  return capped;
+}
+
+/**
+ * Computes metrics for sentences with individual durations, analyzed as one transcript, plus
+ * the spread of speaking rates. words_per_minute counts only the words of sentences with a
+ * positive duration, over the sum of those durations, and stops at maxWords like the metrics.
+ */
+export function computeMetricsTimed(
+  sentences: TimedSentence[],
+  options: MetricsOptions = {}
+): Metrics {
+  const text = sentences.map(([sentence]) => sentence).join(' ');
+  const resolved = resolveOptions(text, options);
+  const kept = capSentences(sentences, resolved);
+  const timed = kept.filter(hasValidDuration);
+  const durationSecs = timed.reduce((total, [, duration]) => total + duration, 0);
+  const metrics = computeMetrics(text, undefined, resolved);
+  const words_per_minute = calculateWordsPerMinute(timedWordCount(timed, resolved), durationSecs);
+  return {
+    ...metrics,
+    words_per_minute,
+    ...calculateFluencyMetrics({ ...metrics, words_per_minute }),
+    rate_variability: rateVariability(kept, resolved),
+  };
+}
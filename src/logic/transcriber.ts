@@ -9,6 +9,10 @@ export interface TranscriptionResult {
   text: string;
   words: TranscriptionWord[];
   audioBlob?: Blob;
+  /** 0-1 model confidence, when the model reports one. */
+  confidence?: number;
+  /** Set when confidence is low enough that re-recording is advisable. */
+  warning?: string;
 }
 
 export interface ITranscriber {
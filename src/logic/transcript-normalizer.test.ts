@@ -0,0 +1,38 @@
+import { describe, it, expect } from 'vitest';
+import { normalizeTranscript, normalizeTranscriptWithOffsets } from './transcript-normalizer';
+
+describe('transcript-normalizer', () => {
+  it('converts smart quotes to ASCII', () => {
+    expect(normalizeTranscript('“I’m fine,” she said ‘quietly’')).toBe(
+      '"I\'m fine," she said \'quietly\''
+    );
+  });
+
+  it('normalizes en and em dashes', () => {
+    expect(normalizeTranscript('pages 1–5 — roughly')).toBe('pages 1 - 5 - roughly');
+    expect(normalizeTranscript('wait—what')).toBe('wait - what');
+  });
+
+  it('collapses and trims whitespace', () => {
+    expect(normalizeTranscript('  hello   there\n\tfriend  ')).toBe('hello there friend');
+  });
+
+  it('removes direction marks and zero-width characters', () => {
+    expect(normalizeTranscript('\u200Fمرحبا\u200F بكم\u200B')).toBe('مرحبا بكم');
+  });
+
+  it('maps each normalized position back to the original text', () => {
+    const text = ' “Hi”  —  there';
+    const { text: normalized, offsets } = normalizeTranscriptWithOffsets(text);
+    expect(normalized).toBe('"Hi" - there');
+    expect(offsets).toHaveLength(normalized.length + 1);
+    expect(text.slice(offsets[1], offsets[3])).toBe('Hi');
+    expect(text.slice(offsets[normalized.indexOf('there')])).toBe('there');
+    expect(offsets[normalized.length]).toBe(text.length);
+  });
+
+  it('is idempotent', () => {
+    const once = normalizeTranscript('  “Well”  —  it’s   done ');
+    expect(normalizeTranscript(once)).toBe(once);
+  });
+});
@@ -0,0 +1,65 @@
+const SINGLE_QUOTE_PATTERN = /[‘’‚‛′]/g;
+const DOUBLE_QUOTE_PATTERN = /[“”„‟″]/g;
+const DASH_PATTERN = /\s*[‒–—―]\s*/g;
+const WHITESPACE_RUN_PATTERN = /\s+/g;
+const EDGE_SPACE_PATTERN = /^ | $/g;
+/** Zero-width characters and bidirectional controls, common around right-to-left text. */
+const INVISIBLE_FORMAT_PATTERN = /[\u200B-\u200F\u061C\u202A-\u202E\u2066-\u2069\uFEFF]/g;
+
+/**
+ * Normalized text with, for each UTF-16 index `i`, `offsets[i]` the index in the original
+ * text it came from. A final entry holds the original length.
+ */
+export interface NormalizedText {
+  text: string;
+  offsets: number[];
+}
+
+const STEPS: [RegExp, string][] = [
+  [INVISIBLE_FORMAT_PATTERN, ''],
+  [SINGLE_QUOTE_PATTERN, "'"],
+  [DOUBLE_QUOTE_PATTERN, '"'],
+  [DASH_PATTERN, ' - '],
+  [WHITESPACE_RUN_PATTERN, ' '],
+  [EDGE_SPACE_PATTERN, ''],
+];
+
+/** Replaces every match, mapping replacement characters to where their match began. */
+function replaceTracked(input: NormalizedText, pattern: RegExp, replacement: string) {
+  const output: NormalizedText = { text: '', offsets: [] };
+  const keep = (from: number, to: number) => {
+    output.text += input.text.slice(from, to);
+    for (let i = from; i < to; i++) output.offsets.push(input.offsets[i]);
+  };
+  let last = 0;
+  for (const match of input.text.matchAll(pattern)) {
+    const index = match.index ?? 0;
+    keep(last, index);
+    output.text += replacement;
+    for (let i = 0; i < replacement.length; i++) output.offsets.push(input.offsets[index]);
+    last = index + match[0].length;
+  }
+  keep(last, input.text.length + 1);
+  return output;
+}
+
+/**
+ * normalizeTranscript, also mapping each position of the result back to the original text so
+ * offsets found in the normalized text can index what the caller passed in.
+ */
+export function normalizeTranscriptWithOffsets(text: string): NormalizedText {
+  const original = { text, offsets: Array.from({ length: text.length + 1 }, (_, i) => i) };
+  return STEPS.reduce(
+    (state, [pattern, replacement]) => replaceTracked(state, pattern, replacement),
+    original
+  );
+}
+
+/**
+ * Cleans up ASR formatting noise: smart quotes become ASCII quotes, figure/en/em dashes
+ * become spaced hyphens (so they never join words) and whitespace runs collapse to a single
+ * space. Invisible direction marks and zero-width characters are removed. Idempotent.
+ */
+export function normalizeTranscript(text: string): string {
+  return STEPS.reduce((result, [pattern, step]) => result.replace(pattern, step), text);
+}
@@ -0,0 +1,29 @@
+import { describe, it, expect } from 'vitest';
+import {
+  confidenceFields,
+  LOW_CONFIDENCE_WARNING,
+  transcriptionConfidence,
+} from './transcription-confidence';
+
+describe('transcription-confidence', () => {
+  it('converts the average log-probability to a probability', () => {
+    expect(transcriptionConfidence({ avg_logprob: Math.log(0.8) })).toBeCloseTo(0.8);
+  });
+
+  it('falls back to the language-detection probability', () => {
+    expect(transcriptionConfidence({ language_probability: 0.9 })).toBe(0.9);
+  });
+
+  it('is undefined when the model reports no confidence', () => {
+    expect(transcriptionConfidence({})).toBeUndefined();
+    expect(confidenceFields({})).toEqual({});
+  });
+
+  it('adds a warning only when confidence is low', () => {
+    expect(confidenceFields({ language_probability: 0.9 })).toEqual({ confidence: 0.9 });
+    expect(confidenceFields({ language_probability: 0.2 })).toEqual({
+      confidence: 0.2,
+      warning: LOW_CONFIDENCE_WARNING,
+    });
+  });
+});
@@ -0,0 +1,35 @@
+/** Below this confidence the user is prompted to re-record. */
+export const LOW_CONFIDENCE_THRESHOLD = 0.5;
+
+export const LOW_CONFIDENCE_WARNING =
+  'The transcription may be inaccurate. Try recording again somewhere quieter.';
+
+/** Confidence signals some Whisper backends include alongside the transcript. */
+export interface WhisperConfidence {
+  avg_logprob?: number;
+  language_probability?: number;
+}
+
+/**
+ * A 0-1 confidence from the model output: the per-token probability implied by the average
+ * log-probability, or failing that the language-detection probability. Undefined when the
+ * model reports neither.
+ */
+export function transcriptionConfidence(output: WhisperConfidence): number | undefined {
+  const { avg_logprob, language_probability } = output;
+  if (avg_logprob !== undefined && Number.isFinite(avg_logprob)) {
+    return Math.exp(Math.min(avg_logprob, 0));
+  }
+  return Number.isFinite(language_probability) ? language_probability : undefined;
+}
+
+/** The confidence and, when it is low, a warning to show the user; empty when unknown. */
+export function confidenceFields(output: WhisperConfidence): {
+  confidence?: number;
+  warning?: string;
+} {
+  const confidence = transcriptionConfidence(output);
+  if (confidence === undefined) return {};
+  if (confidence >= LOW_CONFIDENCE_THRESHOLD) return { confidence };
+  return { confidence, warning: LOW_CONFIDENCE_WARNING };
+}
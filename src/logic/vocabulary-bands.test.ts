@@ -0,0 +1,34 @@
+import { describe, it, expect } from 'vitest';
+import { bandCounts, wordBand } from './vocabulary-bands';
+
+describe('vocabulary-bands', () => {
+  describe('wordBand', () => {
+    it('places words in the lowest band that lists them', () => {
+      expect(wordBand('the')).toBe('A1');
+      expect(wordBand('comprehensive')).toBe('C1');
+    });
+
+    it('matches inflected forms through their stem', () => {
+      expect(wordBand('careers')).toBe('B1');
+    });
+
+    it('reports unlisted words as unknown', () => {
+      expect(wordBand('xylophone')).toBe('unknown');
+      expect(wordBand('the', 'fr')).toBe('unknown');
+    });
+  });
+
+  describe('bandCounts', () => {
+    it('counts every band, including empty ones', () => {
+      expect(bandCounts(['the', 'the', 'comprehensive', 'xylophone'])).toEqual({
+        A1: 2,
+        A2: 0,
+        B1: 0,
+        B2: 0,
+        C1: 1,
+        C2: 0,
+        unknown: 1,
+      });
+    });
+  });
+});
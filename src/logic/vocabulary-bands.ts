@@ -0,0 +1,42 @@
+import { A1_WORD_LIST, A2_WORD_LIST } from './common-words';
+import { DEFAULT_LANGUAGE, isCommonWord, type Language } from './vocabulary';
+
+export const UNKNOWN_BAND = 'unknown';
+
+/**
+ * English word lists per CEFR band. A1 and A2 come from the common-word list; the higher
+ * bands are only seeded with a few examples for now.
+ */
+const ENGLISH_BANDS: [string, ReadonlySet<string>][] = [
+  ['A1', new Set(A1_WORD_LIST)],
+  ['A2', new Set(A2_WORD_LIST)],
+  ['B1', new Set(['achieve', 'advantage', 'career', 'culture', 'environment', 'opinion'])],
+  ['B2', new Set(['approach', 'assess', 'consequence', 'contribute', 'evidence', 'perspective'])],
+  ['C1', new Set(['ambiguous', 'comprehensive', 'inevitable', 'undermine', 'unprecedented'])],
+  ['C2', new Set(['idiosyncratic', 'meticulous', 'obfuscate', 'quintessential', 'ubiquitous'])],
+];
+
+const BANDS_BY_LANGUAGE: Partial<Record<Language, [string, ReadonlySet<string>][]>> = {
+  en: ENGLISH_BANDS,
+};
+
+/** The lowest band listing the word or its stem, or "unknown". */
+export function wordBand(word: string, language: Language = DEFAULT_LANGUAGE): string {
+  const bands = BANDS_BY_LANGUAGE[language] ?? [];
+  return bands.find(([, words]) => isCommonWord(word, words))?.[0] ?? UNKNOWN_BAND;
+}
+
+/**
+ * Counts words per vocabulary band, always including every band and "unknown".
+ * Languages without banded lists report every word as unknown.
+ */
+export function bandCounts(
+  words: string[],
+  language: Language = DEFAULT_LANGUAGE
+): Record<string, number> {
+  const counts: Record<string, number> = {};
+  for (const [band] of ENGLISH_BANDS) counts[band] = 0;
+  counts[UNKNOWN_BAND] = 0;
+  for (const word of words) counts[wordBand(word, language)]++;
+  return counts;
+}
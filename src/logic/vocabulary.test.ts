@@ -0,0 +1,87 @@
+import { describe, it, expect } from 'vitest';
+import {
+  commonWordCount,
+  getCommonWords,
+  getStemmedVocabulary,
+  normalizeVocabulary,
+} from './vocabulary';
+import { COMMON_WORDS, COMMON_WORD_LIST } from './common-words';
+import { SPANISH_COMMON_WORD_LIST } from './common-words-es';
+import { FRENCH_COMMON_WORD_LIST } from './common-words-fr';
+import { GERMAN_COMMON_WORD_LIST } from './common-words-de';
+import { ITALIAN_COMMON_WORD_LIST } from './common-words-it';
+import { ARABIC_COMMON_WORD_LIST } from './common-words-ar';
+import { HINDI_COMMON_WORD_LIST } from './common-words-hi';
+
+describe('vocabulary', () => {
+  describe('getCommonWords', () => {
+    it('defaults to English', () => {
+      expect(getCommonWords()).toBe(COMMON_WORDS);
+    });
+
+    it('returns a list for each supported language', () => {
+      expect(getCommonWords('en').has('the')).toBe(true);
+      expect(getCommonWords('es').has('también')).toBe(true);
+      expect(getCommonWords('fr').has('être')).toBe(true);
+      expect(getCommonWords('de').has('schön')).toBe(true);
+      expect(getCommonWords('it').has('perché')).toBe(true);
+      expect(getCommonWords('ar').has('كتاب')).toBe(true);
+      expect(getCommonWords('hi').has('किताब')).toBe(true);
+    });
+
+    it('stores German nouns lowercase to match transcript words', () => {
+      expect(GERMAN_COMMON_WORD_LIST.every((word) => word === word.toLowerCase())).toBe(true);
+    });
+
+    it('keeps languages separate', () => {
+      expect(getCommonWords('en').has('también')).toBe(false);
+      expect(getCommonWords('es').has('the')).toBe(false);
+    });
+  });
+
+  describe('common word lists', () => {
+    it('have no duplicate entries', () => {
+      const lists = [
+        COMMON_WORD_LIST,
+        SPANISH_COMMON_WORD_LIST,
+        FRENCH_COMMON_WORD_LIST,
+        GERMAN_COMMON_WORD_LIST,
+        ITALIAN_COMMON_WORD_LIST,
+        ARABIC_COMMON_WORD_LIST,
+        HINDI_COMMON_WORD_LIST,
+      ];
+      for (const list of lists) {
+        expect(new Set(list).size).toBe(list.length);
+      }
+    });
+  });
+
+  describe('commonWordCount', () => {
+    it('reports the size of each list', () => {
+      expect(commonWordCount()).toBe(COMMON_WORD_LIST.length);
+      expect(commonWordCount('es')).toBe(SPANISH_COMMON_WORD_LIST.length);
+    });
+  });
+
+  describe('normalizeVocabulary', () => {
+    it('lowercases entries and strips punctuation', () => {
+      const vocab = normalizeVocabulary(['Kubernetes', "Docker's", 'micro-service']);
+      expect([...vocab]).toEqual(['kubernetes', 'dockers', 'microservice']);
+    });
+
+    it('drops entries without letters', () => {
+      expect(normalizeVocabulary(['', '123', '--']).size).toBe(0);
+    });
+  });
+
+  describe('getStemmedVocabulary', () => {
+    it('contains the stems of each word', () => {
+      const stemmed = getStemmedVocabulary(new Set(['walking', 'apartments']));
+      expect([...stemmed]).toEqual(['walk', 'apartment']);
+    });
+
+    it('returns the cached set for the same vocabulary', () => {
+      expect(getStemmedVocabulary(getCommonWords())).toBe(getStemmedVocabulary(getCommonWords()));
+    });
+  });
+});
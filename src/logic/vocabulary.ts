@@ -0,0 +1,67 @@
+import { COMMON_WORDS } from './common-words';
+import { SPANISH_COMMON_WORDS } from './common-words-es';
+import { FRENCH_COMMON_WORDS } from './common-words-fr';
+import { GERMAN_COMMON_WORDS } from './common-words-de';
+import { ITALIAN_COMMON_WORDS } from './common-words-it';
+import { ARABIC_COMMON_WORDS } from './common-words-ar';
+import { HINDI_COMMON_WORDS } from './common-words-hi';
+import { stem } from './stemming';
+
+export type Language = 'en' | 'es' | 'fr' | 'de' | 'it' | 'ar' | 'hi';
+
+export const DEFAULT_LANGUAGE: Language = 'en';
+
+const COMMON_WORDS_BY_LANGUAGE: Record<Language, ReadonlySet<string>> = {
+  en: COMMON_WORDS,
+  es: SPANISH_COMMON_WORDS,
+  fr: FRENCH_COMMON_WORDS,
+  de: GERMAN_COMMON_WORDS,
+  it: ITALIAN_COMMON_WORDS,
+  ar: ARABIC_COMMON_WORDS,
+  hi: HINDI_COMMON_WORDS,
+};
+
+export function getCommonWords(language: Language = DEFAULT_LANGUAGE): ReadonlySet<string> {
+  return COMMON_WORDS_BY_LANGUAGE[language];
+}
+
+export function commonWordCount(language: Language = DEFAULT_LANGUAGE): number {
+  return getCommonWords(language).size;
+}
+
+const NON_LETTER_PATTERN = /[^\p{L}\p{M}]/gu;
+
+/**
+ * Prepares a caller-supplied word list for matching: entries are lowercased and
+ * stripped of non-letters (combining vowel signs are kept), the same way transcript words
+ * are normalized.
+ */
+export function normalizeVocabulary(words: Iterable<string>): ReadonlySet<string> {
+  const normalized = new Set<string>();
+  for (const word of words) {
+    const clean = word.toLowerCase().replace(NON_LETTER_PATTERN, '');
+    if (clean) normalized.add(clean);
+  }
+  return normalized;
+}
+
+const stemmedVocabularies = new WeakMap<ReadonlySet<string>, ReadonlySet<string>>();
+
+/**
+ * Stems of every word in a vocabulary, built once per vocabulary and cached.
+ */
+export function getStemmedVocabulary(words: ReadonlySet<string>): ReadonlySet<string> {
+  let stemmed = stemmedVocabularies.get(words);
+  if (!stemmed) {
+    stemmed = new Set(Array.from(words, (word) => stem(word)));
+    stemmedVocabularies.set(words, stemmed);
+  }
+  return stemmed;
+}
+
+/**
+ * Whether a normalized word, or its stem, appears in the vocabulary.
+ */
+export function isCommonWord(word: string, commonWords: ReadonlySet<string>): boolean {
+  return commonWords.has(word) || getStemmedVocabulary(commonWords).has(stem(word));
+}
@@ -0,0 +1,78 @@
+import { describe, it, expect } from 'vitest';
+import { characterCount, wordCount } from './word-count';
+import { computeMetrics } from './metrics-calculator';
+
+describe('word-count', () => {
+  const samples = ['', 'Hello world', "I don't know, it's “fine”.", 'naïve café 👋'];
+
+  it('matches the word count from computeMetrics', () => {
+    for (const text of samples) {
+      expect(wordCount(text)).toBe(computeMetrics(text).word_count);
+    }
+  });
+
+  it('applies the same number handling as computeMetrics', () => {
+    const options = { numberHandling: 'count' as const };
+    expect(wordCount('I ran 5 km', options)).toBe(4);
+  });
+
+  it('stops at maxWords like computeMetrics', () => {
+    const options = { maxWords: 3 };
+    const text = 'The cat sat on the mat.';
+    expect(wordCount(text, options)).toBe(3);
+    expect(wordCount(text, options)).toBe(computeMetrics(text, undefined, options).word_count);
+  });
+
+  it('counts hyphenated and apostrophe-joined forms under each policy', () => {
+    const text = 'mother-in-law isn’t here';
+    const counts = {
+      'single-token': [3, 2],
+      'split-hyphens': [5, 2],
+      'split-contractions': [4, 3],
+    } as const;
+    for (const [wordCountPolicy, [english, french]] of Object.entries(counts)) {
+      const options = { wordCountPolicy: wordCountPolicy as keyof typeof counts };
+      expect(wordCount(text, options)).toBe(english);
+      expect(computeMetrics(text, undefined, options).word_count).toBe(english);
+      expect(wordCount("l'homme arrive", { ...options, language: 'fr' })).toBe(french);
+    }
+  });
+
+  it('counts single tokens by default', () => {
+    expect(wordCount('mother-in-law isn’t here')).toBe(3);
+    expect(wordCount('mother-in-law isn’t here', { language: 'es' })).toBe(3);
+  });
+
+  it("counts can't as one word unless contractions are split", () => {
+    expect(wordCount("can't")).toBe(1);
+    expect(wordCount("can't", { wordCountPolicy: 'split-hyphens' })).toBe(1);
+    expect(wordCount("can't", { wordCountPolicy: 'split-contractions' })).toBe(2);
+  });
+
+  it('uses the policy for the ratios as well as the count', () => {
+    const options = { wordCountPolicy: 'split-hyphens' as const };
+    const metrics = computeMetrics('A well-known well-being plan', undefined, options);
+    expect(metrics.word_count).toBe(6);
+    expect(metrics.type_token_ratio).toBeCloseTo(metrics.unique_words / metrics.word_count);
+    expect(metrics.unique_word_list).toEqual(['a', 'being', 'known', 'plan', 'well']);
+  });
+
+  it('applies numberHandling before splitting', () => {
+    const options = {
+      numberHandling: 'expand' as const,
+      wordCountPolicy: 'split-hyphens' as const,
+    };
+    expect(wordCount('I am 21', options)).toBe(4);
+    expect(wordCount('I am 21', { numberHandling: 'expand' })).toBe(3);
+  });
+
+  it('matches the character count from computeMetrics', () => {
+    for (const text of samples) {
+      expect(characterCount(text)).toBe(computeMetrics(text).character_count);
+    }
+  });
+
+  it('counts an emoji as one character', () => {
+    expect(characterCount('hi 👋')).toBe(4);
+  });
+});
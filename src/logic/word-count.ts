@@ -0,0 +1,23 @@
+import { analysisWords, capWords, prepareText, resolveOptions } from './metrics-options';
+import type { MetricsOptions } from './metrics-types';
+
+export { characterCount } from './text-utils';
+
+/**
+ * How tokens count towards word_count, complex_words and every per-word ratio. 'single-token',
+ * the default, counts "well-being", "can't" and "John's" as one word each; 'split-hyphens'
+ * counts each hyphenated part ("mother-in-law" is three words); 'split-contractions' expands
+ * English contractions ("can't" is "can not") and counts each remaining apostrophe-joined part
+ * ("John's" is two).
+ */
+export type WordCountPolicy = 'single-token' | 'split-hyphens' | 'split-contractions';
+
+/**
+ * Live word count matching Metrics.word_count. Runs the same normalization and tokenizer
+ * as computeMetrics, including the maxWords cap, but skips every set, map and scoring pass.
+ */
+export function wordCount(text: string, options: MetricsOptions = {}): number {
+  const resolved = resolveOptions(text, options);
+  const capped = capWords(text, resolved).text;
+  return analysisWords(prepareText(capped, resolved), resolved).length;
+}
@@ -0,0 +1,36 @@
+import { describe, it, expect } from 'vitest';
+import { COMPLEX_DIFFICULTY, wordDifficulties, wordDifficulty } from './word-difficulty';
+import { isComplexWord } from './cefr-scoring';
+
+describe('word-difficulty', () => {
+  const vocab = new Set(['the', 'house']);
+
+  it('scores common words lowest and long unfamiliar words highest', () => {
+    const common = wordDifficulty('house', vocab);
+    const unfamiliar = wordDifficulty('gluon', vocab);
+    const long = wordDifficulty('kubernetes', vocab);
+    expect(common).toBeLessThanOrEqual(0.25);
+    expect(unfamiliar).toBeGreaterThan(common);
+    expect(unfamiliar).toBeLessThan(COMPLEX_DIFFICULTY);
+    expect(long).toBeGreaterThan(COMPLEX_DIFFICULTY);
+    expect(long).toBeLessThanOrEqual(1);
+  });
+
+  it('grades longer, multi-syllable words higher within a tier', () => {
+    expect(wordDifficulty('extraordinarily', vocab)).toBeGreaterThan(
+      wordDifficulty('kubernetes', vocab)
+    );
+  });
+
+  it('treats academic suffixes as complex and agrees with isComplexWord', () => {
+    for (const word of ['nation', 'gluon', 'the', 'well-known', 'kubernetes', '']) {
+      expect(wordDifficulty(word, vocab) > COMPLEX_DIFFICULTY).toBe(isComplexWord(word, vocab));
+    }
+    expect(isComplexWord('nation', vocab)).toBe(true);
+  });
+
+  it('lists each distinct word once in order of appearance', () => {
+    const difficulties = wordDifficulties(['the', 'gluon', 'the'], vocab);
+    expect(difficulties.map(([word]) => word)).toEqual(['the', 'gluon']);
+  });
+});
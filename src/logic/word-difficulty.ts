@@ -0,0 +1,48 @@
+import { countSyllables } from './syllables';
+import { getCommonWords, isCommonWord } from './vocabulary';
+
+/** Words scoring above this are counted as complex. */
+export const COMPLEX_DIFFICULTY = 0.5;
+
+const MIN_WORD_LENGTH_FOR_COMPLEXITY = 9;
+const ACADEMIC_SUFFIX_PATTERN = /((tion)|(ment)|(ence)|(ance)|(ity)|(ive)|(ous)|(ism)|(ist))$/;
+const NON_LETTER_PATTERN = /[^\p{L}\p{M}]/gu;
+const MAX_SCORED_LENGTH = 12;
+const MAX_SCORED_EXTRA_SYLLABLES = 3;
+
+/** 0-1 blend of word length and syllable count, ignoring familiarity. */
+function formScore(word: string): number {
+  const length = Math.min([...word].length / MAX_SCORED_LENGTH, 1);
+  const syllables = Math.min((countSyllables(word) - 1) / MAX_SCORED_EXTRA_SYLLABLES, 1);
+  return (length + Math.max(syllables, 0)) / 2;
+}
+
+/**
+ * A 0-1 difficulty combining familiarity, length and syllables. Common words score at most
+ * 0.25 and other words up to 0.5, unless they are long or carry an academic suffix
+ * ("-tion", "-ity"), which puts them above COMPLEX_DIFFICULTY. Length and syllables grade
+ * words within each tier. A hyphenated word is as difficult as its hardest part.
+ */
+export function wordDifficulty(
+  word: string,
+  commonWords: ReadonlySet<string> = getCommonWords()
+): number {
+  if (word.includes('-')) {
+    return Math.max(...word.split('-').map((part) => wordDifficulty(part, commonWords)));
+  }
+  const clean = word.toLowerCase().replace(NON_LETTER_PATTERN, '');
+  if (!clean) return 0;
+  const form = formScore(clean);
+  if (isCommonWord(clean, commonWords)) return form / 4;
+  const isLong = clean.length > MIN_WORD_LENGTH_FOR_COMPLEXITY;
+  if (isLong || ACADEMIC_SUFFIX_PATTERN.test(clean)) return (1 + form) / 2;
+  return (1 + form) / 4;
+}
+
+/** Difficulty of each distinct word, in order of first appearance. */
+export function wordDifficulties(
+  words: string[],
+  commonWords: ReadonlySet<string> = getCommonWords()
+): [string, number][] {
+  return [...new Set(words)].map((word) => [word, wordDifficulty(word, commonWords)]);
+}